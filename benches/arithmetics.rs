@@ -24,5 +24,14 @@ fn bench_multiply(c: &mut Criterion) {
     c.bench_function("multiply", |b| b.iter(|| Decimal::max() * one));
 }
 
-criterion_group!(benches, bench_add, bench_multiply);
+fn bench_sum(c: &mut Criterion) {
+    let vals: Vec<Decimal> = (0..100)
+        .map(|i| Decimal::with_scale(i * 37 - 1500, 2))
+        .collect();
+    c.bench_function("sum", |b| {
+        b.iter(|| black_box(&vals).iter().sum::<Decimal>())
+    });
+}
+
+criterion_group!(benches, bench_add, bench_multiply, bench_sum);
 criterion_main!(benches);
@@ -0,0 +1,221 @@
+//! Conversions to and from the decimal types of Apache Arrow.
+//!
+//! Arrow stores decimals as two's-complement integers (128-bit for Decimal128 and 256-bit for
+//! Decimal256) that hold the value multiplied by `10^scale`. The precision limits the number of
+//! decimal digits of these integers.
+
+use arrow_buffer::i256;
+use TryFromNumberError;
+
+/// Maximal precision of `Decimal128` values.
+pub const DECIMAL128_MAX_PRECISION: u8 = 38;
+
+/// Maximal precision of `Decimal256` values.
+pub const DECIMAL256_MAX_PRECISION: u8 = 76;
+
+/// Creates a `Decimal256` value of the given precision from a magnitude in little-endian order.
+pub fn to_i256(
+    neg: bool,
+    magnitude: &[u8; 32],
+    precision: u8,
+    max_precision: u8,
+) -> Result<i256, TryFromNumberError> {
+    assert!(
+        precision >= 1 && precision <= max_precision,
+        "precision must lie in the range 1..={}",
+        max_precision
+    );
+    let v = i256::from_le_bytes(*magnitude);
+    if v.is_negative() || v >= i256::from_i128(10).wrapping_pow(precision as u32) {
+        return Err(TryFromNumberError::Overflow);
+    }
+    Ok(if neg { v.wrapping_neg() } else { v })
+}
+
+/// Splits a `Decimal256` value into the sign and the magnitude in little-endian order.
+pub fn from_i256(v: i256) -> (bool, [u8; 32]) {
+    // The absolute value of i256::MIN wraps to itself, which has the right bit pattern
+    (v.is_negative(), v.wrapping_abs().to_le_bytes())
+}
+
+/// Generates conversions between an fdec type and the Arrow decimal types.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_arrow {
+    ($name:ident) => {
+        impl $name {
+            /// Converts the number to an Arrow `Decimal128` value of the given precision and scale.
+            ///
+            /// The number is rounded with the given mode if it has more decimal places than `scale`.
+            /// Fails with `TryFromNumberError::Overflow` if the result has more than `precision` digits,
+            /// and with `TryFromNumberError::Special` if the number is NaN or ±Infinity.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `precision` is not in the range `1..=38`.
+            pub fn to_arrow_decimal128(
+                &self,
+                precision: u8,
+                scale: i8,
+                mode: RoundingMode,
+            ) -> Result<i128, TryFromNumberError> {
+                let (neg, mag) = self.to_arrow_magnitude(scale, mode)?;
+                $crate::arrow::to_i256(
+                    neg,
+                    &mag,
+                    precision,
+                    $crate::arrow::DECIMAL128_MAX_PRECISION,
+                )
+                .map(|v| v.as_i128())
+            }
+
+            /// Converts the number to an Arrow `Decimal256` value of the given precision and scale.
+            ///
+            /// The number is rounded with the given mode if it has more decimal places than `scale`.
+            /// Fails with `TryFromNumberError::Overflow` if the result has more than `precision` digits,
+            /// and with `TryFromNumberError::Special` if the number is NaN or ±Infinity.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `precision` is not in the range `1..=76`.
+            pub fn to_arrow_decimal256(
+                &self,
+                precision: u8,
+                scale: i8,
+                mode: RoundingMode,
+            ) -> Result<$crate::arrow_buffer::i256, TryFromNumberError> {
+                let (neg, mag) = self.to_arrow_magnitude(scale, mode)?;
+                $crate::arrow::to_i256(
+                    neg,
+                    &mag,
+                    precision,
+                    $crate::arrow::DECIMAL256_MAX_PRECISION,
+                )
+            }
+
+            /// Creates a number from an Arrow `Decimal128` value of the given scale. The value is rounded
+            /// with the given mode if `scale` is larger than the scale of the type.
+            ///
+            /// Fails with `TryFromNumberError::Overflow` if the value doesn't fit into the type.
+            pub fn from_arrow_decimal128(
+                v: i128,
+                scale: i8,
+                mode: RoundingMode,
+            ) -> Result<Self, TryFromNumberError> {
+                let (neg, mag) = $crate::arrow::from_i256($crate::arrow_buffer::i256::from_i128(v));
+                $name::from_le_magnitude_scaled(neg, &mag, scale as isize, mode)
+            }
+
+            /// Creates a number from an Arrow `Decimal256` value of the given scale. The value is rounded
+            /// with the given mode if `scale` is larger than the scale of the type.
+            ///
+            /// Fails with `TryFromNumberError::Overflow` if the value doesn't fit into the type.
+            pub fn from_arrow_decimal256(
+                v: $crate::arrow_buffer::i256,
+                scale: i8,
+                mode: RoundingMode,
+            ) -> Result<Self, TryFromNumberError> {
+                let (neg, mag) = $crate::arrow::from_i256(v);
+                $name::from_le_magnitude_scaled(neg, &mag, scale as isize, mode)
+            }
+
+            /// Scales the magnitude of the number to the given scale and returns it as 256-bit
+            /// little-endian integer together with the sign.
+            fn to_arrow_magnitude(
+                &self,
+                scale: i8,
+                mode: RoundingMode,
+            ) -> Result<(bool, [u8; 32]), TryFromNumberError> {
+                if self.is_special() {
+                    return Err(TryFromNumberError::Special);
+                }
+                let neg = self.is_sign_negative();
+
+                // Extra units keep the scaled magnitude until it's known to exceed 256 bits
+                let mut mag = self.magnitude.to_vec();
+                mag.resize(M_LENGTH + 32 / UNIT_BYTES, 0);
+                let shift = scale as isize - $name::SCALE as isize;
+                if shift < 0 {
+                    let rem = shift_decimal_right(&mut mag, (-shift) as usize);
+                    if mode.increments(neg, mag[0] & 1 == 1, rem) {
+                        add_unit(&mut mag, 1);
+                    }
+                } else {
+                    for _ in 0..shift {
+                        if bit_length(&mag) > 256 {
+                            return Err(TryFromNumberError::Overflow);
+                        }
+                        multiply_by_unit(&mut mag, 10);
+                    }
+                }
+                if bit_length(&mag) > 256 {
+                    return Err(TryFromNumberError::Overflow);
+                }
+                let mut bytes = [0; 32];
+                for (chunk, u) in bytes.chunks_mut(UNIT_BYTES).zip(mag.iter()) {
+                    chunk.copy_from_slice(&u.to_le_bytes());
+                }
+                Ok((neg, bytes))
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn magnitude(v: u128) -> [u8; 32] {
+        let mut res = [0; 32];
+        res[..16].copy_from_slice(&v.to_le_bytes());
+        res
+    }
+
+    #[test]
+    fn test_to_i256() {
+        assert_eq!(
+            to_i256(false, &magnitude(12345), 5, 38),
+            Ok(i256::from_i128(12345))
+        );
+        assert_eq!(
+            to_i256(true, &magnitude(12345), 5, 38),
+            Ok(i256::from_i128(-12345))
+        );
+        assert_eq!(
+            to_i256(false, &magnitude(99999), 5, 38),
+            Ok(i256::from_i128(99999))
+        );
+        assert_eq!(
+            to_i256(false, &magnitude(100000), 5, 38),
+            Err(TryFromNumberError::Overflow)
+        );
+        let max = 10u128.pow(38) - 1;
+        assert_eq!(
+            to_i256(true, &magnitude(max), 38, 38),
+            Ok(i256::from_i128(-(max as i128)))
+        );
+        assert_eq!(
+            to_i256(false, &[0xff; 32], 76, 76),
+            Err(TryFromNumberError::Overflow)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_to_i256_precision() {
+        let _ = to_i256(false, &magnitude(1), 39, 38);
+    }
+
+    #[test]
+    fn test_from_i256() {
+        assert_eq!(from_i256(i256::from_i128(-5)), (true, magnitude(5)));
+        assert_eq!(
+            from_i256(i256::from_i128(i128::MIN)),
+            (true, magnitude(1 << 127))
+        );
+        let (neg, mag) = from_i256(i256::MIN);
+        assert!(neg);
+        assert_eq!(mag[31], 0x80);
+        assert!(mag[..31].iter().all(|b| *b == 0));
+    }
+}
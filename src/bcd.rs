@@ -0,0 +1,156 @@
+//! Packed BCD (COBOL COMP-3) encoding.
+//!
+//! Every byte holds two decimal digits, one per nibble, and the last nibble holds the sign:
+//! `C` for positive, `D` for negative and `F` for unsigned values. A field of `n` digits takes
+//! `n / 2 + 1` bytes, fields with an even number of digits start with a zero nibble.
+
+use PackedBcdError;
+
+const SIGN_POSITIVE: u8 = 0x0c;
+const SIGN_NEGATIVE: u8 = 0x0d;
+const SIGN_UNSIGNED: u8 = 0x0f;
+
+/// Packs decimal digits, listed least significant first, into a field of the given number of digits.
+/// The caller must check that the digits fit into the field.
+pub fn pack(neg: bool, digits: &[u8], len: usize) -> Vec<u8> {
+    debug_assert!(digits.len() <= len);
+    let mut res = vec![0; len / 2 + 1];
+    let last = res.len() - 1;
+    res[last] = if neg { SIGN_NEGATIVE } else { SIGN_POSITIVE };
+    // Nibble `i` counts from the sign nibble, which has index 0
+    for (i, d) in digits.iter().enumerate() {
+        let n = i + 1;
+        res[last - n / 2] |= if n % 2 == 0 { *d } else { *d << 4 };
+    }
+    res
+}
+
+/// Unpacks a field into the sign and its decimal digits, listed most significant first.
+pub fn unpack(bytes: &[u8]) -> Result<(bool, Vec<u8>), PackedBcdError> {
+    let (last, head) = bytes.split_last().ok_or(PackedBcdError::Empty)?;
+    let neg = match last & 0x0f {
+        SIGN_POSITIVE | SIGN_UNSIGNED => false,
+        SIGN_NEGATIVE => true,
+        sign => return Err(PackedBcdError::InvalidSign(sign)),
+    };
+    let mut digits = Vec::with_capacity(2 * bytes.len() - 1);
+    for b in head {
+        digits.push(b >> 4);
+        digits.push(b & 0x0f);
+    }
+    digits.push(last >> 4);
+    match digits.iter().position(|d| *d > 9) {
+        Some(i) => Err(PackedBcdError::InvalidDigit(i)),
+        None => Ok((neg, digits)),
+    }
+}
+
+/// Generates conversions between an fdec type and packed BCD.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_packed_bcd {
+    ($name:ident) => {
+        impl $name {
+            /// Converts the number to a packed BCD (COBOL `COMP-3`) field of `digits` decimal digits, `scale`
+            /// of which are decimal places. The field takes `digits / 2 + 1` bytes, and its sign nibble is
+            /// `C` for positive numbers and zero and `D` for negative numbers.
+            ///
+            /// Fails with `TryFromNumberError::Fraction` if the number has non-zero digits below `scale`,
+            /// with `TryFromNumberError::Overflow` if it doesn't fit into the field, and with
+            /// `TryFromNumberError::Special` if it is NaN or ±Infinity.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `scale` is greater than `digits`.
+            pub fn to_packed_bcd(
+                &self,
+                digits: usize,
+                scale: usize,
+            ) -> Result<Vec<u8>, TryFromNumberError> {
+                assert!(
+                    scale <= digits,
+                    "scale must not exceed the number of digits"
+                );
+                if self.is_special() {
+                    return Err(TryFromNumberError::Special);
+                }
+                let mut mag = self.magnitude;
+                if shift_decimal_right(&mut mag, $name::SCALE.saturating_sub(scale))
+                    != Remainder::Zero
+                {
+                    return Err(TryFromNumberError::Fraction);
+                }
+                let mut bcd_digits = vec![0; scale.saturating_sub($name::SCALE)];
+                if !is_magnitude_zero(&mag) {
+                    bcd_digits.extend(to_digits(mag.to_vec()));
+                }
+                if bcd_digits.len() > digits {
+                    return Err(TryFromNumberError::Overflow);
+                }
+                Ok($crate::bcd::pack(
+                    self.is_sign_negative(),
+                    &bcd_digits,
+                    digits,
+                ))
+            }
+
+            /// Creates a number from a packed BCD (COBOL `COMP-3`) field that has `scale` decimal places.
+            /// Sign nibbles `C` and `F` denote positive values, and `D` denotes negative values.
+            ///
+            /// Fails with `PackedBcdError::InvalidDigit` or `PackedBcdError::InvalidSign` if the field has
+            /// invalid nibbles, with `PackedBcdError::Fraction` if the value has non-zero digits below the
+            /// scale of the type, and with `PackedBcdError::Overflow` if the value doesn't fit into the type.
+            pub fn from_packed_bcd(bytes: &[u8], scale: usize) -> Result<Self, PackedBcdError> {
+                let (neg, digits) = $crate::bcd::unpack(bytes)?;
+                let kept = digits
+                    .len()
+                    .saturating_sub(scale.saturating_sub($name::SCALE));
+                if digits[kept..].iter().any(|d| *d != 0) {
+                    return Err(PackedBcdError::Fraction);
+                }
+                let exp = digits.len() as i64 - scale as i64;
+                if exp > i32::MAX as i64 || exp < i32::MIN as i64 {
+                    return Err(PackedBcdError::Overflow);
+                }
+                let num = $name::from_decimal_digits(neg, &digits, exp as i32, RoundingMode::Down);
+                if num.is_infinite() {
+                    return Err(PackedBcdError::Overflow);
+                }
+                Ok(num)
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pack() {
+        assert_eq!(pack(false, &[5, 4, 3, 2, 1], 5), [0x12, 0x34, 0x5c]);
+        assert_eq!(pack(true, &[5, 4, 3, 2, 1], 6), [0x00, 0x12, 0x34, 0x5d]);
+        assert_eq!(pack(false, &[7], 1), [0x7c]);
+        assert_eq!(pack(false, &[], 3), [0x00, 0x0c]);
+    }
+
+    #[test]
+    fn test_unpack() {
+        assert_eq!(
+            unpack(&[0x12, 0x34, 0x5c]),
+            Ok((false, vec![1, 2, 3, 4, 5]))
+        );
+        assert_eq!(
+            unpack(&[0x00, 0x12, 0x34, 0x5d]),
+            Ok((true, vec![0, 0, 1, 2, 3, 4, 5]))
+        );
+        assert_eq!(unpack(&[0x7f]), Ok((false, vec![7])));
+        assert_eq!(unpack(&[]), Err(PackedBcdError::Empty));
+        assert_eq!(
+            unpack(&[0x12, 0x3a]),
+            Err(PackedBcdError::InvalidSign(0x0a))
+        );
+        assert_eq!(unpack(&[0x1a, 0x3c]), Err(PackedBcdError::InvalidDigit(1)));
+        assert_eq!(unpack(&[0x12, 0xfc]), Err(PackedBcdError::InvalidDigit(2)));
+    }
+}
@@ -0,0 +1,56 @@
+// Implementation of `bincode` 2 traits for the generated types.
+//
+// Numbers are encoded as their little-endian byte representation (see `to_le_bytes()`),
+// regardless of the configured endianness and integer encoding. Decoding validates the flags.
+
+use bincode_crate::error::DecodeError;
+use FromBytesError;
+
+/// Converts an error of reading a number from bytes to a decoding error.
+pub fn decode_error(e: FromBytesError) -> DecodeError {
+    DecodeError::Other(match e {
+        FromBytesError::InvalidFlags => "invalid number flags",
+        FromBytesError::BufferTooShort => "number is too short",
+        FromBytesError::Overflow => "number overflow",
+        FromBytesError::InvalidFormat => "invalid number format",
+    })
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_bincode {
+    ($name:ident) => {
+        impl $crate::bincode_crate::Encode for $name {
+            #[inline]
+            fn encode<E: $crate::bincode_crate::enc::Encoder>(
+                &self,
+                encoder: &mut E,
+            ) -> Result<(), $crate::bincode_crate::error::EncodeError> {
+                $crate::bincode_crate::enc::write::Writer::write(
+                    encoder.writer(),
+                    &self.to_le_bytes(),
+                )
+            }
+        }
+
+        impl<C> $crate::bincode_crate::Decode<C> for $name {
+            fn decode<D: $crate::bincode_crate::de::Decoder<Context = C>>(
+                decoder: &mut D,
+            ) -> Result<Self, $crate::bincode_crate::error::DecodeError> {
+                decoder.claim_bytes_read(BYTE_ARRAY_LEN)?;
+                let mut bytes = [0; BYTE_ARRAY_LEN];
+                $crate::bincode_crate::de::read::Reader::read(decoder.reader(), &mut bytes)?;
+                $name::from_le_bytes(&bytes).map_err($crate::bincode::decode_error)
+            }
+        }
+
+        impl<'de, C> $crate::bincode_crate::BorrowDecode<'de, C> for $name {
+            #[inline]
+            fn borrow_decode<D: $crate::bincode_crate::de::BorrowDecoder<'de, Context = C>>(
+                decoder: &mut D,
+            ) -> Result<Self, $crate::bincode_crate::error::DecodeError> {
+                $crate::bincode_crate::Decode::decode(decoder)
+            }
+        }
+    };
+}
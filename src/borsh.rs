@@ -0,0 +1,36 @@
+// Implementation of `borsh` traits for the generated types.
+//
+// Numbers are encoded as their little-endian byte representation (see `to_le_bytes()`).
+// Decoding validates the flags.
+
+use std::io;
+use FromBytesError;
+
+/// Converts an error of reading a number from bytes to an I/O error.
+pub fn invalid_data(e: FromBytesError) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("invalid number: {:?}", e),
+    )
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_borsh {
+    ($name:ident) => {
+        impl $crate::borsh_crate::BorshSerialize for $name {
+            #[inline]
+            fn serialize<W: ::std::io::Write>(&self, writer: &mut W) -> ::std::io::Result<()> {
+                writer.write_all(&self.to_le_bytes())
+            }
+        }
+
+        impl $crate::borsh_crate::BorshDeserialize for $name {
+            fn deserialize_reader<R: ::std::io::Read>(reader: &mut R) -> ::std::io::Result<Self> {
+                let mut bytes = [0; BYTE_ARRAY_LEN];
+                reader.read_exact(&mut bytes)?;
+                $name::from_le_bytes(&bytes).map_err($crate::borsh::invalid_data)
+            }
+        }
+    };
+}
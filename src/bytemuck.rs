@@ -0,0 +1,27 @@
+// Implementation of `bytemuck` traits for the generated types.
+//
+// The types have a `repr(C)` layout without padding, so they can be viewed as bytes. Not every bit
+// pattern is a valid number, so casting from bytes goes through `CheckedBitPattern`, which validates
+// the flags.
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_bytemuck {
+    ($name:ident) => {
+        // SAFETY: all zeros is the representation of zero.
+        unsafe impl $crate::bytemuck_crate::Zeroable for $name {}
+
+        // SAFETY: the type is `repr(C)`, consists of units of the same type, and has no padding.
+        unsafe impl $crate::bytemuck_crate::NoUninit for $name {}
+
+        // SAFETY: `Bits` has the same size and alignment as the type, and only valid numbers are accepted.
+        unsafe impl $crate::bytemuck_crate::CheckedBitPattern for $name {
+            type Bits = [Unit; M_LENGTH + 1];
+
+            #[inline]
+            fn is_valid_bit_pattern(bits: &Self::Bits) -> bool {
+                $name::is_valid_repr(bits)
+            }
+        }
+    };
+}
@@ -0,0 +1,429 @@
+//! Conversion between binary fractions and primitive float types.
+
+use std::cmp::Ordering;
+
+/// Composes the bits of a positive float value that is the closest to `q * 2^exp`.
+/// `q` must have its highest bit set. `sticky` tells that there are non-zero bits below `q`,
+/// i.e. the actual value is a bit greater than `q * 2^exp`. Ties are rounded to even.
+/// `frac_bits` is the number of the fraction bits in the float type, `max_exp` is its
+/// maximum exponent.
+#[inline]
+fn compose(q: u64, exp: i32, sticky: bool, frac_bits: u32, max_exp: i32) -> u64 {
+    debug_assert!(q >> 63 == 1);
+    let min_exp = 1 - max_exp;
+    let e = exp + 63; // Exponent of the highest bit of `q`
+    if e > max_exp {
+        return infinity_bits(frac_bits);
+    }
+
+    // Subnormal values have a fixed exponent and lose more bits
+    let (base, shift) = if e < min_exp {
+        (0, (63 - frac_bits) as i64 + (min_exp - e) as i64)
+    } else {
+        ((e - min_exp) as u64, (63 - frac_bits) as i64)
+    };
+    if shift > 64 {
+        return 0; // Less than half of the smallest subnormal value
+    }
+
+    let wide = q as u128;
+    let mut mant = (wide >> shift) as u64;
+    let rem = wide & ((1u128 << shift) - 1);
+    let half = 1u128 << (shift - 1);
+    if rem > half || (rem == half && (sticky || mant & 1 == 1)) {
+        mant += 1;
+    }
+
+    // The hidden bit of `mant` adds one to the exponent field, and so does the carry after rounding
+    let bits = (base << frac_bits) + mant;
+    if bits >= infinity_bits(frac_bits) {
+        infinity_bits(frac_bits)
+    } else {
+        bits
+    }
+}
+
+#[inline(always)]
+fn infinity_bits(frac_bits: u32) -> u64 {
+    let exp_bits = if frac_bits == 52 { 11 } else { 8 };
+    ((1 << exp_bits) - 1) << frac_bits
+}
+
+/// Returns the `f64` value that is the closest to `±q * 2^exp`.
+/// See `compose()` for details.
+pub fn compose_f64(neg: bool, q: u64, exp: i32, sticky: bool) -> f64 {
+    let bits = compose(q, exp, sticky, 52, 1023);
+    f64::from_bits(bits | if neg { 1 << 63 } else { 0 })
+}
+
+/// Returns the `f32` value that is the closest to `±q * 2^exp`.
+/// See `compose()` for details.
+pub fn compose_f32(neg: bool, q: u64, exp: i32, sticky: bool) -> f32 {
+    let bits = compose(q, exp, sticky, 23, 127) as u32;
+    f32::from_bits(bits | if neg { 1 << 31 } else { 0 })
+}
+
+/// Maximum number of significant decimal digits in the shortest representation of a float value.
+pub const MAX_DIGITS: usize = 17;
+
+/// Splits a finite non-zero `f64` value into its absolute mantissa `m` and exponent `exp`,
+/// so that `|v| = m * 2^exp`.
+pub fn decompose_f64(v: f64) -> (u64, i32) {
+    let bits = v.to_bits();
+    let frac = bits & ((1 << 52) - 1);
+    match ((bits >> 52) & 0x7ff) as i32 {
+        0 => (frac, -1074),
+        e => (frac | 1 << 52, e - 1075),
+    }
+}
+
+/// Splits a finite non-zero `f32` value into its absolute mantissa `m` and exponent `exp`,
+/// so that `|v| = m * 2^exp`.
+pub fn decompose_f32(v: f32) -> (u64, i32) {
+    let bits = v.to_bits();
+    let frac = (bits & ((1 << 23) - 1)) as u64;
+    match ((bits >> 23) & 0xff) as i32 {
+        0 => (frac, -149),
+        e => (frac | 1 << 23, e - 150),
+    }
+}
+
+/// Writes the shortest decimal digits that uniquely identify the given finite non-zero `f64` value
+/// into `digits`. Returns the number of the digits `n` and the exponent `k`, so that
+/// `|v| ≈ 0.d1d2...dn * 10^k`.
+pub fn shortest_f64(v: f64, digits: &mut [u8; MAX_DIGITS]) -> (usize, i32) {
+    let (m, exp) = decompose_f64(v);
+    shortest(m, exp, m == 1 << 52 && exp > -1074, digits)
+}
+
+/// Writes the shortest decimal digits that uniquely identify the given finite non-zero `f32` value
+/// into `digits`. Returns the number of the digits `n` and the exponent `k`, so that
+/// `|v| ≈ 0.d1d2...dn * 10^k`.
+pub fn shortest_f32(v: f32, digits: &mut [u8; MAX_DIGITS]) -> (usize, i32) {
+    let (m, exp) = decompose_f32(v);
+    shortest(m, exp, m == 1 << 23 && exp > -149, digits)
+}
+
+/// Generates the shortest digits of the value `m * 2^exp` that still round to it.
+/// `lower_closer` tells that the gap to the previous float value is half of the gap to the next one.
+/// R. G. Burger, R. K. Dybvig, "Printing Floating-Point Numbers Quickly and Accurately", free-format algorithm.
+fn shortest(m: u64, exp: i32, lower_closer: bool, digits: &mut [u8; MAX_DIGITS]) -> (usize, i32) {
+    debug_assert!(m != 0);
+    // v = r / s, the distances to the neighbor values are m_plus / s and m_minus / s
+    let lc = lower_closer as u32;
+    let (e_pos, e_neg) = if exp >= 0 {
+        (exp as u32, 0)
+    } else {
+        (0, exp.unsigned_abs())
+    };
+    let mut r = Big::from_u64(m);
+    r.mul_pow2(1 + lc + e_pos);
+    let mut s = Big::from_u64(1);
+    s.mul_pow2(1 + lc + e_neg);
+    let mut m_plus = Big::from_u64(1);
+    m_plus.mul_pow2(lc + e_pos);
+    let mut m_minus = Big::from_u64(1);
+    m_minus.mul_pow2(e_pos);
+
+    // Boundaries round to even mantissas, so they are included in the range if `m` is even
+    let inclusive = m & 1 == 0;
+    let reaches = |r: &Big, m: &Big, s: &Big| match r.add(m).cmp(s) {
+        Ordering::Greater => true,
+        Ordering::Equal => inclusive,
+        Ordering::Less => false,
+    };
+
+    // The estimate is either exact or one less than ceil(log10(v))
+    let bits = exp + 64 - m.leading_zeros() as i32 - 1;
+    let mut k = (bits as f64 * std::f64::consts::LOG10_2 - 1e-10).ceil() as i32;
+    if k >= 0 {
+        s.mul_pow10(k as u32);
+    } else {
+        r.mul_pow10(k.unsigned_abs());
+        m_plus.mul_pow10(k.unsigned_abs());
+        m_minus.mul_pow10(k.unsigned_abs());
+    }
+    if reaches(&r, &m_plus, &s) {
+        k += 1;
+    } else {
+        r.mul_small(10);
+        m_plus.mul_small(10);
+        m_minus.mul_small(10);
+    }
+
+    let mut n = 0;
+    loop {
+        let mut d = 0;
+        while r.cmp(&s) != Ordering::Less {
+            r.sub_assign(&s);
+            d += 1;
+        }
+        let low = match r.cmp(&m_minus) {
+            Ordering::Less => true,
+            Ordering::Equal => inclusive,
+            Ordering::Greater => false,
+        };
+        let high = reaches(&r, &m_plus, &s);
+        if !low && !high {
+            digits[n] = d;
+            n += 1;
+            r.mul_small(10);
+            m_plus.mul_small(10);
+            m_minus.mul_small(10);
+            continue;
+        }
+        let up = if low && high {
+            r.add(&r).cmp(&s) != Ordering::Less
+        } else {
+            high
+        };
+        digits[n] = d + up as u8;
+        return (n + 1, k);
+    }
+}
+
+/// Number of 32-bit words in `Big`, enough to hold `2^1077 * 10^324` and `10^310`.
+const BIG_LENGTH: usize = 40;
+
+/// Fixed-size unsigned integer for the shortest digits generation.
+#[derive(Clone, Copy)]
+struct Big {
+    words: [u32; BIG_LENGTH], // Little-endian order
+}
+
+impl Big {
+    fn from_u64(v: u64) -> Big {
+        let mut words = [0; BIG_LENGTH];
+        words[0] = v as u32;
+        words[1] = (v >> 32) as u32;
+        Big { words }
+    }
+
+    fn mul_small(&mut self, v: u32) {
+        let mut carry = 0;
+        for w in self.words.iter_mut() {
+            let m = *w as u64 * v as u64 + carry;
+            *w = m as u32;
+            carry = m >> 32;
+        }
+        debug_assert!(carry == 0);
+    }
+
+    fn mul_pow2(&mut self, n: u32) {
+        let words = (n / 32) as usize;
+        let bits = n % 32;
+        debug_assert!(self.words[BIG_LENGTH - words..].iter().all(|w| *w == 0));
+        for i in (0..BIG_LENGTH).rev() {
+            let mut w = if i >= words {
+                self.words[i - words] << bits
+            } else {
+                0
+            };
+            if bits > 0 && i > words {
+                w |= self.words[i - words - 1] >> (32 - bits);
+            }
+            self.words[i] = w;
+        }
+    }
+
+    fn mul_pow10(&mut self, mut n: u32) {
+        while n >= 9 {
+            self.mul_small(1_000_000_000);
+            n -= 9;
+        }
+        self.mul_small(10u32.pow(n));
+    }
+
+    fn add(&self, rhs: &Big) -> Big {
+        let mut res = *self;
+        let mut carry = 0;
+        for (w, r) in res.words.iter_mut().zip(rhs.words.iter()) {
+            let s = *w as u64 + *r as u64 + carry;
+            *w = s as u32;
+            carry = s >> 32;
+        }
+        debug_assert!(carry == 0);
+        res
+    }
+
+    /// Subtracts `rhs` from the value. The caller must check that the value is not less than `rhs`.
+    fn sub_assign(&mut self, rhs: &Big) {
+        let mut borrow = false;
+        for (w, r) in self.words.iter_mut().zip(rhs.words.iter()) {
+            let (d, b1) = w.overflowing_sub(*r);
+            let (d, b2) = d.overflowing_sub(borrow as u32);
+            *w = d;
+            borrow = b1 || b2;
+        }
+        debug_assert!(!borrow);
+    }
+
+    fn cmp(&self, rhs: &Big) -> Ordering {
+        self.words.iter().rev().cmp(rhs.words.iter().rev())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ONE: u64 = 1 << 63;
+
+    #[test]
+    fn test_compose_f64_exact() {
+        assert_eq!(compose_f64(false, ONE, -63, false), 1.0);
+        assert_eq!(compose_f64(true, ONE, -63, false), -1.0);
+        assert_eq!(compose_f64(false, ONE | (ONE >> 1), -62, false), 3.0);
+        assert_eq!(compose_f64(false, ONE, 960, false), 2f64.powi(1023));
+        assert_eq!(compose_f64(false, ONE, -1085, false), f64::MIN_POSITIVE);
+        assert_eq!(compose_f64(false, ONE, -1137, false), f64::from_bits(1));
+    }
+
+    #[test]
+    fn test_compose_f64_rounding() {
+        // 1 + 2^-53 is a tie between 1 and 1 + 2^-52
+        assert_eq!(compose_f64(false, ONE | 1 << 10, -63, false), 1.0);
+        assert_eq!(
+            compose_f64(false, ONE | 1 << 10, -63, true),
+            1.0 + f64::EPSILON
+        );
+        // 1 + 3 * 2^-53 is a tie between 1 + 2^-52 and 1 + 2^-51
+        assert_eq!(
+            compose_f64(false, ONE | 3 << 10, -63, false),
+            1.0 + 2.0 * f64::EPSILON
+        );
+        assert_eq!(compose_f64(false, u64::MAX, -64, false), 1.0);
+        assert_eq!(compose_f64(false, u64::MAX, -65, false), 0.5);
+    }
+
+    #[test]
+    fn test_compose_f64_overflow() {
+        assert_eq!(compose_f64(false, ONE, 961, false), f64::INFINITY);
+        assert_eq!(compose_f64(true, ONE, 961, false), f64::NEG_INFINITY);
+        assert_eq!(compose_f64(false, u64::MAX, 960, false), f64::INFINITY);
+        assert_eq!(
+            compose_f64(false, u64::MAX - (1 << 10), 960, false),
+            f64::MAX
+        );
+    }
+
+    #[test]
+    fn test_compose_f64_subnormal() {
+        let min = f64::from_bits(1);
+        assert_eq!(compose_f64(false, ONE, -1138, false), 0.0);
+        assert_eq!(compose_f64(false, ONE, -1138, true), min);
+        assert_eq!(compose_f64(false, ONE, -1139, true), 0.0);
+        assert_eq!(compose_f64(false, ONE | ONE >> 1, -1138, false), min);
+        assert_eq!(compose_f64(false, ONE | 1, -1200, false), 0.0);
+        assert_eq!(compose_f64(true, ONE, -1137, false), -min);
+        assert_eq!(
+            compose_f64(false, u64::MAX, -1086, false),
+            f64::MIN_POSITIVE
+        );
+    }
+
+    #[test]
+    fn test_compose_f32() {
+        assert_eq!(compose_f32(false, ONE, -63, false), 1.0);
+        assert_eq!(compose_f32(true, ONE, -65, false), -0.25);
+        assert_eq!(compose_f32(false, ONE | 1 << 39, -63, false), 1.0);
+        assert_eq!(
+            compose_f32(false, ONE | 1 << 39, -63, true),
+            1.0 + f32::EPSILON
+        );
+        assert_eq!(compose_f32(false, ONE, 64, false), 2f32.powi(127));
+        assert_eq!(compose_f32(false, ONE, 65, false), f32::INFINITY);
+        assert_eq!(compose_f32(false, ONE, -212, false), f32::from_bits(1));
+        assert_eq!(compose_f32(false, ONE, -213, false), 0.0);
+    }
+
+    // Formats the shortest digits the same way as `{:e}` does
+    fn format_digits(digits: &[u8], k: i32) -> String {
+        let mut s = String::new();
+        for (i, d) in digits.iter().enumerate() {
+            if i == 1 {
+                s.push('.');
+            }
+            s.push((b'0' + d) as char);
+        }
+        format!("{}e{}", s, k - 1)
+    }
+
+    fn shortest_f64_str(v: f64) -> String {
+        let mut digits = [0; MAX_DIGITS];
+        let (n, k) = shortest_f64(v, &mut digits);
+        format_digits(&digits[..n], k)
+    }
+
+    fn shortest_f32_str(v: f32) -> String {
+        let mut digits = [0; MAX_DIGITS];
+        let (n, k) = shortest_f32(v, &mut digits);
+        format_digits(&digits[..n], k)
+    }
+
+    #[test]
+    fn test_decompose() {
+        assert_eq!(decompose_f64(1.0), (1 << 52, -52));
+        assert_eq!(decompose_f64(-0.75), (3 << 51, -53));
+        assert_eq!(decompose_f64(f64::from_bits(1)), (1, -1074));
+        assert_eq!(decompose_f64(f64::MAX), ((1 << 53) - 1, 971));
+        assert_eq!(decompose_f32(1.0), (1 << 23, -23));
+        assert_eq!(decompose_f32(f32::from_bits(3)), (3, -149));
+    }
+
+    #[test]
+    fn test_shortest_f64() {
+        assert_eq!(shortest_f64_str(1.0), "1e0");
+        assert_eq!(shortest_f64_str(0.1), "1e-1");
+        assert_eq!(shortest_f64_str(0.3), "3e-1");
+        assert_eq!(shortest_f64_str(0.1 + 0.2), "3.0000000000000004e-1");
+        assert_eq!(shortest_f64_str(123.456), "1.23456e2");
+        assert_eq!(shortest_f64_str(1e23), "1e23");
+        assert_eq!(shortest_f64_str(f64::MAX), "1.7976931348623157e308");
+        assert_eq!(
+            shortest_f64_str(f64::MIN_POSITIVE),
+            "2.2250738585072014e-308"
+        );
+        assert_eq!(shortest_f64_str(f64::from_bits(1)), "5e-324");
+        assert_eq!(
+            shortest_f64_str(2f64.powi(-1022)),
+            "2.2250738585072014e-308"
+        );
+    }
+
+    #[test]
+    fn test_shortest_matches_std() {
+        // Pseudo-random bit patterns cover normal and subnormal values of all magnitudes
+        let mut x: u64 = 0x2545_f491_4f6c_dd1d;
+        for _ in 0..5000 {
+            x ^= x << 13;
+            x ^= x >> 7;
+            x ^= x << 17;
+            let v = f64::from_bits(x).abs();
+            if v.is_finite() && v != 0.0 {
+                assert_eq!(shortest_f64_str(v), format!("{:e}", v));
+            }
+            let v = f32::from_bits(x as u32).abs();
+            if v.is_finite() && v != 0.0 {
+                assert_eq!(shortest_f32_str(v), format!("{:e}", v));
+            }
+        }
+        // All powers of two, from 2^-1074 to 2^1023
+        for e in 0..2098 {
+            let v = if e < 52 {
+                f64::from_bits(1 << e)
+            } else {
+                f64::from_bits((e - 51) << 52)
+            };
+            assert_eq!(shortest_f64_str(v), format!("{:e}", v));
+        }
+    }
+
+    #[test]
+    fn test_shortest_f32() {
+        assert_eq!(shortest_f32_str(0.1), "1e-1");
+        assert_eq!(shortest_f32_str(16777216.0), "1.6777216e7");
+        assert_eq!(shortest_f32_str(f32::MAX), "3.4028235e38");
+        assert_eq!(shortest_f32_str(f32::from_bits(1)), "1e-45");
+    }
+}
@@ -0,0 +1,271 @@
+//! Interoperability with the common types of Google APIs.
+//!
+//! Every generated type can be converted to and from the `(units, nanos)` pair of
+//! [`google.type.Money`](https://github.com/googleapis/googleapis/blob/master/google/type/money.proto)
+//! with `to_units_nanos()` and `from_units_nanos()`, and created from strings of
+//! [`google.type.Decimal`](https://github.com/googleapis/googleapis/blob/master/google/type/decimal.proto)
+//! with `from_google_decimal()`. Formatting a finite number with `to_string()` always produces a valid
+//! `google.type.Decimal` string.
+//!
+//! ```
+//! # #[macro_use] extern crate fdec;
+//! fdec64! {
+//!     module dec,
+//!     name Decimal,
+//!     length 2,
+//!     scale 12
+//! }
+//!
+//! use dec::*;
+//!
+//! # fn main() {
+//! let price = dec!(-1999, 3);
+//! assert_eq!(price.to_units_nanos(RoundingMode::HalfEven), Ok((-1, -999_000_000)));
+//! assert_eq!(Decimal::from_units_nanos(-1, -999_000_000, RoundingMode::HalfEven), Ok(price));
+//!
+//! assert!(fdec::google::is_valid_decimal("-1.999e0"));
+//! assert_eq!(Decimal::from_google_decimal("-1.999e0", RoundingMode::HalfEven), Ok(price));
+//! # }
+//! ```
+
+/// Number of nanos in one unit.
+#[doc(hidden)]
+pub const NANOS_PER_UNIT: u128 = 1_000_000_000;
+
+/// Decimal places of nanos.
+#[doc(hidden)]
+pub const NANOS_SCALE: usize = 9;
+
+// Limit of exponents, which keeps their arithmetic from overflowing. Values with larger exponents
+// either overflow or round to zero for any type anyway.
+const EXPONENT_LIMIT: i64 = 1 << 30;
+
+/// Checks if the string matches the grammar of `google.type.Decimal`:
+///
+/// ```text
+/// DecimalString = [Sign] Significand [Exponent];
+/// Sign = '+' | '-';
+/// Significand = Digits '.' | [Digits] '.' Digits | Digits;
+/// Exponent = ('e' | 'E') [Sign] Digits;
+/// Digits = { '0' | '1' | '2' | '3' | '4' | '5' | '6' | '7' | '8' | '9' };
+/// ```
+///
+/// Special values like `NaN` and `Infinity` are not part of the grammar.
+pub fn is_valid_decimal(s: &str) -> bool {
+    parse_decimal(s).is_some()
+}
+
+/// Splits a `google.type.Decimal` string into the sign, the decimal digits of the significand and
+/// the exponent, so that the value is `±0.d1d2...dn * 10^exp`. Returns `None` if the string doesn't
+/// match the grammar.
+#[doc(hidden)]
+pub fn parse_decimal(s: &str) -> Option<(bool, Vec<u8>, i32)> {
+    let bytes = s.as_bytes();
+    let (neg, mut i) = match bytes.first() {
+        Some(b'-') => (true, 1),
+        Some(b'+') => (false, 1),
+        _ => (false, 0),
+    };
+    let mut digits = Vec::with_capacity(bytes.len());
+    while i < bytes.len() && bytes[i].is_ascii_digit() {
+        digits.push(bytes[i] - b'0');
+        i += 1;
+    }
+    let int_digits = digits.len() as i64;
+    if i < bytes.len() && bytes[i] == b'.' {
+        i += 1;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            digits.push(bytes[i] - b'0');
+            i += 1;
+        }
+    }
+    if digits.is_empty() {
+        return None;
+    }
+    let mut exp = 0;
+    if i < bytes.len() && (bytes[i] == b'e' || bytes[i] == b'E') {
+        i += 1;
+        let exp_neg = match bytes.get(i) {
+            Some(b'-') => true,
+            Some(b'+') => false,
+            _ => {
+                i -= 1;
+                false
+            }
+        };
+        i += 1;
+        let start = i;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            exp = (exp * 10 + (bytes[i] - b'0') as i64).min(EXPONENT_LIMIT);
+            i += 1;
+        }
+        if i == start {
+            return None;
+        }
+        if exp_neg {
+            exp = -exp;
+        }
+    }
+    if i != bytes.len() {
+        return None;
+    }
+    let exp = (int_digits.min(EXPONENT_LIMIT) + exp).clamp(-EXPONENT_LIMIT, EXPONENT_LIMIT);
+    Some((neg, digits, exp as i32))
+}
+
+/// Generates conversions between an fdec type and the common types of Google APIs.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_google {
+    ($name:ident) => {
+        impl $name {
+            /// Converts the number to the `(units, nanos)` pair of `google.type.Money`. Both parts have the sign
+            /// of the number, and nanos are in the range `-999_999_999..=999_999_999`.
+            ///
+            /// Numbers with more than 9 decimal places are rounded with the given mode. Fails with
+            /// `TryFromNumberError::Overflow` if units don't fit into `i64`, and with `TryFromNumberError::Special`
+            /// if the number is NaN or ±Infinity.
+            pub fn to_units_nanos(
+                &self,
+                mode: RoundingMode,
+            ) -> Result<(i64, i32), TryFromNumberError> {
+                if self.is_special() {
+                    return Err(TryFromNumberError::Special);
+                }
+                let neg = self.is_sign_negative();
+                let mut mag = self.magnitude;
+                let rem = shift_decimal_right(
+                    &mut mag,
+                    $name::SCALE.saturating_sub($crate::google::NANOS_SCALE),
+                );
+                let mut n = magnitude_to_u128(&mag).ok_or(TryFromNumberError::Overflow)?;
+                if mode.increments(neg, n % 2 == 1, rem) {
+                    n += 1;
+                }
+                let mut scale = $name::SCALE;
+                while scale < $crate::google::NANOS_SCALE {
+                    n = n.checked_mul(10).ok_or(TryFromNumberError::Overflow)?;
+                    scale += 1;
+                }
+                let units = n / $crate::google::NANOS_PER_UNIT;
+                let nanos = (n % $crate::google::NANOS_PER_UNIT) as i32;
+                if neg {
+                    if units > i64::MIN.unsigned_abs() as u128 {
+                        return Err(TryFromNumberError::Overflow);
+                    }
+                    Ok(((units as i64).wrapping_neg(), -nanos))
+                } else {
+                    if units > i64::MAX as u128 {
+                        return Err(TryFromNumberError::Overflow);
+                    }
+                    Ok((units as i64, nanos))
+                }
+            }
+
+            /// Creates a number from the `(units, nanos)` pair of `google.type.Money`. Nanos are rounded with
+            /// the given mode if the type has less than 9 decimal places.
+            ///
+            /// Fails with `TryFromNumberError::Invalid` if nanos are out of the range `-999_999_999..=999_999_999`,
+            /// or if the parts have different signs, and with `TryFromNumberError::Overflow` if the value doesn't
+            /// fit into the type.
+            pub fn from_units_nanos(
+                units: i64,
+                nanos: i32,
+                mode: RoundingMode,
+            ) -> Result<Self, TryFromNumberError> {
+                if nanos.unsigned_abs() as u128 >= $crate::google::NANOS_PER_UNIT
+                    || (units > 0 && nanos < 0)
+                    || (units < 0 && nanos > 0)
+                {
+                    return Err(TryFromNumberError::Invalid);
+                }
+                let neg = units < 0 || nanos < 0;
+                let mut n = units.unsigned_abs() as u128 * $crate::google::NANOS_PER_UNIT
+                    + nanos.unsigned_abs() as u128;
+                let mut rem = Remainder::Zero;
+                let mut scale = $name::SCALE;
+                while scale < $crate::google::NANOS_SCALE {
+                    rem = Remainder::from_digit((n % 10) as u8, rem != Remainder::Zero);
+                    n /= 10;
+                    scale += 1;
+                }
+                if mode.increments(neg, n % 2 == 1, rem) {
+                    n += 1;
+                }
+                let scale = $name::SCALE.min($crate::google::NANOS_SCALE);
+                $name::from_le_magnitude_bytes(neg, &n.to_le_bytes(), scale)
+                    .ok_or(TryFromNumberError::Overflow)
+            }
+
+            /// Creates a number from a string of `google.type.Decimal`, which may have an exponent. Values with
+            /// more decimal places than the type are rounded with the given mode.
+            ///
+            /// Fails with `ParseNumberError::InvalidFormat` if the string doesn't match the grammar (see
+            /// [`is_valid_decimal`](../google/fn.is_valid_decimal.html)), and with `ParseNumberError::Overflow`
+            /// if the value doesn't fit into the type.
+            pub fn from_google_decimal(
+                s: &str,
+                mode: RoundingMode,
+            ) -> Result<Self, ParseNumberError> {
+                let (neg, digits, exp) =
+                    $crate::google::parse_decimal(s).ok_or(ParseNumberError::InvalidFormat)?;
+                if digits.iter().all(|d| *d == 0) {
+                    return Ok($name::ZERO);
+                }
+                let num = $name::from_decimal_digits(neg, &digits, exp, mode);
+                if num.is_infinite() {
+                    return Err(ParseNumberError::Overflow);
+                }
+                Ok(num)
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid_decimal() {
+        for s in &[
+            "0",
+            "-0",
+            "+0",
+            "1.",
+            ".5",
+            "-.5",
+            "+1.25",
+            "00012.3400",
+            "1e5",
+            "1E5",
+            "1.5e-3",
+            ".5E+10",
+            "5.e3",
+        ] {
+            assert!(is_valid_decimal(s), "{}", s);
+        }
+        for s in &[
+            "", "-", "+", ".", "-.", "e5", ".e5", "1e", "1e+", "1.2.3", "1,5", " 1", "1 ", "--1",
+            "1e5.5", "NaN", "Infinity", "0x10", "1_000",
+        ] {
+            assert!(!is_valid_decimal(s), "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_parse_decimal() {
+        assert_eq!(parse_decimal("12.5"), Some((false, vec![1, 2, 5], 2)));
+        assert_eq!(parse_decimal("-.05"), Some((true, vec![0, 5], 0)));
+        assert_eq!(parse_decimal("1.5e-3"), Some((false, vec![1, 5], -2)));
+        assert_eq!(parse_decimal("7.E+2"), Some((false, vec![7], 3)));
+        assert_eq!(
+            parse_decimal("1e99999999999999999999"),
+            Some((false, vec![1], 1 << 30))
+        );
+        assert_eq!(
+            parse_decimal("1e-99999999999999999999"),
+            Some((false, vec![1], 1 - (1 << 30)))
+        );
+    }
+}
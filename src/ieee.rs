@@ -0,0 +1,477 @@
+//! Encoding and decoding of IEEE 754-2008 decimal interchange formats.
+
+/// Parameters of a decimal interchange format.
+pub struct Format {
+    bits: u32,    // Total number of bits
+    digits: u32,  // Number of decimal digits in the coefficient
+    ec_bits: u32, // Number of exponent continuation bits
+    bias: i32,    // Exponent bias
+}
+
+/// The decimal64 format.
+pub const DECIMAL64: Format = Format {
+    bits: 64,
+    digits: 16,
+    ec_bits: 8,
+    bias: 398,
+};
+
+/// The decimal128 format.
+pub const DECIMAL128: Format = Format {
+    bits: 128,
+    digits: 34,
+    ec_bits: 12,
+    bias: 6176,
+};
+
+/// A value of a decimal interchange format.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Value {
+    /// Finite value `(-1)^neg * coefficient * 10^exponent`.
+    Finite(bool, u128, i32),
+    /// Infinity of the given sign.
+    Infinite(bool),
+    /// NaN, including signaling NaN.
+    NaN,
+}
+
+const COMBINATION_INFINITY: u128 = 0b11110;
+const COMBINATION_NAN: u128 = 0b11111;
+
+impl Format {
+    /// Returns the minimal exponent of finite values.
+    pub fn min_exponent(&self) -> i32 {
+        -self.bias
+    }
+
+    /// Returns the maximal exponent of finite values.
+    pub fn max_exponent(&self) -> i32 {
+        3 * (1 << self.ec_bits) - 1 - self.bias
+    }
+
+    /// Returns the number of decimal digits in the coefficient.
+    pub fn digits(&self) -> u32 {
+        self.digits
+    }
+
+    /// Returns the upper bound of coefficients (exclusive).
+    pub fn coefficient_limit(&self) -> u128 {
+        10u128.pow(self.digits)
+    }
+
+    // Number of bits in the trailing significand field
+    fn trailing_bits(&self) -> u32 {
+        self.bits - 6 - self.ec_bits
+    }
+
+    // Encodes the sign and special values, leaving other bits to the caller
+    fn encode_special(&self, v: &Value) -> Option<u128> {
+        match *v {
+            Value::Finite(..) => None,
+            Value::Infinite(neg) => Some(self.sign(neg) | COMBINATION_INFINITY << (self.bits - 6)),
+            Value::NaN => Some(COMBINATION_NAN << (self.bits - 6)),
+        }
+    }
+
+    // Decodes special values
+    fn decode_special(&self, bits: u128) -> Option<Value> {
+        match (bits >> (self.bits - 6)) & 0b11111 {
+            COMBINATION_INFINITY => Some(Value::Infinite(self.is_negative(bits))),
+            COMBINATION_NAN => Some(Value::NaN),
+            _ => None,
+        }
+    }
+
+    fn sign(&self, neg: bool) -> u128 {
+        (neg as u128) << (self.bits - 1)
+    }
+
+    fn is_negative(&self, bits: u128) -> bool {
+        bits >> (self.bits - 1) & 1 != 0
+    }
+
+    fn biased_exponent(&self, exponent: i32) -> u128 {
+        debug_assert!(self.min_exponent() <= exponent && exponent <= self.max_exponent());
+        (exponent + self.bias) as u128
+    }
+
+    /// Encodes the value using the binary integer decimal (BID) encoding. The value must fit the format.
+    pub fn encode_bid(&self, v: &Value) -> u128 {
+        if let Some(bits) = self.encode_special(v) {
+            return bits;
+        }
+        if let Value::Finite(neg, coefficient, exponent) = *v {
+            debug_assert!(coefficient < self.coefficient_limit());
+            let t = self.trailing_bits();
+            let exponent = self.biased_exponent(exponent);
+            if coefficient < 1 << (t + 3) {
+                self.sign(neg) | exponent << (t + 3) | coefficient
+            } else {
+                // The coefficient starts with bits 100, which are implied
+                self.sign(neg)
+                    | 0b11 << (self.bits - 3)
+                    | exponent << (t + 1)
+                    | (coefficient & ((1 << (t + 1)) - 1))
+            }
+        } else {
+            unreachable!()
+        }
+    }
+
+    /// Decodes a value in the binary integer decimal (BID) encoding.
+    pub fn decode_bid(&self, bits: u128) -> Value {
+        if let Some(v) = self.decode_special(bits) {
+            return v;
+        }
+        let t = self.trailing_bits();
+        let exponent_mask = (1 << (self.ec_bits + 2)) - 1;
+        let (exponent, coefficient) = if bits >> (self.bits - 3) & 0b11 != 0b11 {
+            (bits >> (t + 3) & exponent_mask, bits & ((1 << (t + 3)) - 1))
+        } else {
+            (
+                bits >> (t + 1) & exponent_mask,
+                bits & ((1 << (t + 1)) - 1) | 1 << (t + 3),
+            )
+        };
+        // Non-canonical coefficients are treated as zero
+        let coefficient = if coefficient < self.coefficient_limit() {
+            coefficient
+        } else {
+            0
+        };
+        Value::Finite(
+            self.is_negative(bits),
+            coefficient,
+            exponent as i32 - self.bias,
+        )
+    }
+
+    /// Encodes the value using the densely packed decimal (DPD) encoding. The value must fit the format.
+    pub fn encode_dpd(&self, v: &Value) -> u128 {
+        if let Some(bits) = self.encode_special(v) {
+            return bits;
+        }
+        if let Value::Finite(neg, coefficient, exponent) = *v {
+            debug_assert!(coefficient < self.coefficient_limit());
+            let t = self.trailing_bits();
+            let exponent = self.biased_exponent(exponent);
+            let mut trailing = 0;
+            let mut rest = coefficient;
+            for i in 0..t / 10 {
+                trailing |= encode_declet((rest % 1000) as u16) << (10 * i);
+                rest /= 1000;
+            }
+            let (msd, msbs) = (rest, exponent >> self.ec_bits);
+            let combination = if msd < 8 {
+                msbs << 3 | msd
+            } else {
+                0b11000 | msbs << 1 | (msd & 1)
+            };
+            let continuation = exponent & ((1 << self.ec_bits) - 1);
+            self.sign(neg) | combination << (self.bits - 6) | continuation << t | trailing
+        } else {
+            unreachable!()
+        }
+    }
+
+    /// Decodes a value in the densely packed decimal (DPD) encoding.
+    pub fn decode_dpd(&self, bits: u128) -> Value {
+        if let Some(v) = self.decode_special(bits) {
+            return v;
+        }
+        let t = self.trailing_bits();
+        let combination = bits >> (self.bits - 6) & 0b11111;
+        let (msd, msbs) = if combination >> 3 != 0b11 {
+            (combination & 0b111, combination >> 3)
+        } else {
+            (8 | (combination & 1), combination >> 1 & 0b11)
+        };
+        let exponent = msbs << self.ec_bits | (bits >> t & ((1 << self.ec_bits) - 1));
+        let mut coefficient = msd;
+        for i in (0..t / 10).rev() {
+            coefficient =
+                coefficient * 1000 + decode_declet((bits >> (10 * i) & 0x3ff) as u16) as u128;
+        }
+        Value::Finite(
+            self.is_negative(bits),
+            coefficient,
+            exponent as i32 - self.bias,
+        )
+    }
+}
+
+/// Encodes three decimal digits (a number below 1000) into a 10-bit declet.
+fn encode_declet(v: u16) -> u128 {
+    let (d1, d2, d3) = (v / 100, v / 10 % 10, v % 10);
+    let large = (d1 >= 8, d2 >= 8, d3 >= 8);
+    // Small digits are stored with 3 bits, large ones (8 and 9) with their lowest bit only
+    let (pqr, stu, wxy) = match large {
+        (false, false, false) => (d1, d2, d3),
+        (false, false, true) => (d1, d2, d3 & 1),
+        (false, true, false) => (d1, (d3 & 0b110) | (d2 & 1), 0b010 | (d3 & 1)),
+        (false, true, true) => (d1, 0b100 | (d2 & 1), 0b110 | (d3 & 1)),
+        (true, false, false) => ((d3 & 0b110) | (d1 & 1), d2, 0b100 | (d3 & 1)),
+        (true, false, true) => ((d2 & 0b110) | (d1 & 1), 0b010 | (d2 & 1), 0b110 | (d3 & 1)),
+        (true, true, false) => ((d3 & 0b110) | (d1 & 1), d2 & 1, 0b110 | (d3 & 1)),
+        (true, true, true) => (d1 & 1, 0b110 | (d2 & 1), 0b110 | (d3 & 1)),
+    };
+    let v_bit = if large == (false, false, false) { 0 } else { 1 };
+    (pqr << 7 | stu << 4 | v_bit << 3 | wxy) as u128
+}
+
+/// Decodes a 10-bit declet into a number below 1000.
+fn decode_declet(declet: u16) -> u16 {
+    let (pqr, stu, wxy) = (declet >> 7 & 0b111, declet >> 4 & 0b111, declet & 0b111);
+    let (p_q, s_t) = (pqr & 0b110, stu & 0b110);
+    let (r, u, y) = (pqr & 1, stu & 1, wxy & 1);
+    let (d1, d2, d3) = if declet & 0b1000 == 0 {
+        (pqr, stu, wxy)
+    } else {
+        match (wxy >> 1, s_t >> 1) {
+            (0b00, _) => (pqr, stu, 8 | y),
+            (0b01, _) => (pqr, 8 | u, s_t | y),
+            (0b10, _) => (8 | r, stu, p_q | y),
+            (_, 0b00) => (8 | r, 8 | u, p_q | y),
+            (_, 0b01) => (8 | r, p_q | u, 8 | y),
+            (_, 0b10) => (pqr, 8 | u, 8 | y),
+            _ => (8 | r, 8 | u, 8 | y),
+        }
+    };
+    d1 * 100 + d2 * 10 + d3
+}
+
+/// Generates conversions between an fdec type and the decimal interchange formats.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_ieee_decimal {
+    ($name:ident) => {
+        impl $name {
+            impl_ieee_decimal!(@format $name, decimal64, u64, DECIMAL64, encode_bid, decode_bid, "BID",
+                to_decimal64_bid, from_decimal64_bid);
+            impl_ieee_decimal!(@format $name, decimal64, u64, DECIMAL64, encode_dpd, decode_dpd, "DPD",
+                to_decimal64_dpd, from_decimal64_dpd);
+            impl_ieee_decimal!(@format $name, decimal128, u128, DECIMAL128, encode_bid, decode_bid, "BID",
+                to_decimal128_bid, from_decimal128_bid);
+            impl_ieee_decimal!(@format $name, decimal128, u128, DECIMAL128, encode_dpd, decode_dpd, "DPD",
+                to_decimal128_dpd, from_decimal128_dpd);
+
+            /// Converts the number to a value of the decimal interchange format. The coefficient keeps all
+            /// the digits of the number if they fit, otherwise the number is rounded with the given mode.
+            fn to_ieee_decimal(&self, format: &$crate::ieee::Format, mode: RoundingMode)
+                -> Result<$crate::ieee::Value, TryFromNumberError> {
+                if self.is_nan() {
+                    return Ok($crate::ieee::Value::NaN);
+                }
+                let neg = self.is_sign_negative();
+                if self.is_infinite() {
+                    return Ok($crate::ieee::Value::Infinite(neg));
+                }
+                let limit = format.coefficient_limit();
+                let mut mag = self.magnitude;
+                let mut exponent = -($name::SCALE as i64);
+
+                // Drop the digits that don't fit the coefficient or lie below the minimal exponent.
+                // Most of them are dropped at once, using the lower bound of the number of digits.
+                let bits = bit_length(&mag) as i64;
+                let min_digits = if bits == 0 { 0 } else { (bits - 1) * 30103 / 100000 + 1 };
+                let shift = (min_digits - format.digits() as i64).max(format.min_exponent() as i64 - exponent).max(0);
+                let mut rem = shift_decimal_right(&mut mag, shift as usize);
+                exponent += shift;
+                let mut coefficient = loop {
+                    match magnitude_to_u128(&mag) {
+                        Some(c) if c < limit => break c,
+                        _ => {
+                            rem = Remainder::from_digit(div_rem_unit(&mut mag, 10) as u8, rem != Remainder::Zero);
+                            exponent += 1;
+                        }
+                    }
+                };
+                if mode.increments(neg, coefficient % 2 == 1, rem) {
+                    coefficient += 1;
+                    if coefficient == limit {
+                        coefficient /= 10;
+                        exponent += 1;
+                    }
+                }
+
+                // Large exponents are brought into the range by padding the coefficient with zeros
+                while exponent > format.max_exponent() as i64 && coefficient * 10 < limit {
+                    coefficient *= 10;
+                    exponent -= 1;
+                }
+                if exponent > format.max_exponent() as i64 {
+                    return Err(TryFromNumberError::Overflow);
+                }
+                Ok($crate::ieee::Value::Finite(neg, coefficient, exponent as i32))
+            }
+
+            /// Creates a number from a value of the decimal interchange format, rounding it with the given mode
+            /// if it has more decimal places than the type.
+            fn from_ieee_decimal(v: $crate::ieee::Value, mode: RoundingMode) -> Result<Self, TryFromNumberError> {
+                let (neg, mut coefficient, mut exponent) = match v {
+                    $crate::ieee::Value::NaN => return Ok($name::NAN),
+                    $crate::ieee::Value::Infinite(neg) => {
+                        return Ok(if neg { $name::NEG_INFINITY } else { $name::INFINITY })
+                    }
+                    $crate::ieee::Value::Finite(neg, coefficient, exponent) => (neg, coefficient, exponent as i64),
+                };
+                let scale = $name::SCALE as i64;
+                if exponent < -scale {
+                    let mut shift = -scale - exponent;
+                    let mut rem = Remainder::Zero;
+                    while shift > 0 && coefficient != 0 {
+                        rem = Remainder::from_digit((coefficient % 10) as u8, rem != Remainder::Zero);
+                        coefficient /= 10;
+                        shift -= 1;
+                    }
+                    if shift > 0 && rem != Remainder::Zero {
+                        rem = Remainder::BelowHalf;
+                    }
+                    if mode.increments(neg, coefficient % 2 == 1, rem) {
+                        coefficient += 1;
+                    }
+                    exponent = -scale;
+                }
+                if coefficient == 0 {
+                    return Ok($name::ZERO);
+                }
+                let (scale, up) = if exponent <= 0 { (-exponent, 0) } else { (0, exponent) };
+                let mut num = $name::from_le_magnitude_bytes(neg, &coefficient.to_le_bytes(), scale as usize)
+                    .ok_or(TryFromNumberError::Overflow)?;
+                if num.move_point_right(up as usize) {
+                    return Err(TryFromNumberError::Overflow);
+                }
+                Ok(num)
+            }
+        }
+    };
+    (@format $name:ident, $format:ident, $bits:ty, $params:ident, $encode:ident, $decode:ident, $encoding:expr,
+     $to:ident, $from:ident) => {
+        #[doc = concat!("Converts the number to IEEE 754 `", stringify!($format), "` in the ", $encoding, " encoding.")]
+        ///
+        /// All the digits of the number are kept if they fit into the coefficient, otherwise the number is rounded
+        /// with the given mode. NaN and infinities are converted to the corresponding special values.
+        /// Fails with `TryFromNumberError::Overflow` if the number is too large for the format.
+        pub fn $to(&self, mode: RoundingMode) -> Result<$bits, TryFromNumberError> {
+            let format = &$crate::ieee::$params;
+            self.to_ieee_decimal(format, mode).map(|v| format.$encode(&v) as $bits)
+        }
+
+        #[doc = concat!("Creates a number from IEEE 754 `", stringify!($format), "` in the ", $encoding, " encoding.")]
+        ///
+        /// Values with more decimal places than the type has are rounded with the given mode. Special values are
+        /// converted to the corresponding special values of the type, signaling NaNs are converted to NaN.
+        /// Fails with `TryFromNumberError::Overflow` if the value is too large for the type.
+        pub fn $from(bits: $bits, mode: RoundingMode) -> Result<Self, TryFromNumberError> {
+            $name::from_ieee_decimal($crate::ieee::$params.$decode(bits as u128), mode)
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_declets() {
+        for v in 0..1000 {
+            assert_eq!(decode_declet(encode_declet(v) as u16), v);
+        }
+        assert_eq!(encode_declet(0), 0);
+        assert_eq!(encode_declet(9), 0b00_0000_1001);
+        assert_eq!(encode_declet(750), 0b11_1101_0000);
+        assert_eq!(encode_declet(999), 0b00_1111_1111);
+        // Non-canonical declets decode to large digits
+        assert_eq!(decode_declet(0b11_1111_1111), 999);
+        assert_eq!(decode_declet(0b01_1111_1111), 999);
+    }
+
+    #[test]
+    fn test_exponent_range() {
+        assert_eq!(DECIMAL64.min_exponent(), -398);
+        assert_eq!(DECIMAL64.max_exponent(), 369);
+        assert_eq!(DECIMAL128.min_exponent(), -6176);
+        assert_eq!(DECIMAL128.max_exponent(), 6111);
+    }
+
+    #[test]
+    fn test_decimal64() {
+        let one = Value::Finite(false, 1, 0);
+        assert_eq!(DECIMAL64.encode_bid(&one), 0x31c0_0000_0000_0001);
+        assert_eq!(DECIMAL64.encode_dpd(&one), 0x2238_0000_0000_0001);
+        let max = Value::Finite(false, 9_999_999_999_999_999, 369);
+        assert_eq!(DECIMAL64.encode_bid(&max), 0x77fb_86f2_6fc0_ffff);
+        assert_eq!(DECIMAL64.encode_dpd(&max), 0x77fc_ff3f_cff3_fcff);
+        let v = Value::Finite(true, 750, -2);
+        assert_eq!(DECIMAL64.encode_dpd(&v), 0xa230_0000_0000_03d0);
+        assert_eq!(DECIMAL64.encode_bid(&v), 0xb180_0000_0000_02ee);
+        for v in &[
+            one,
+            max,
+            v,
+            Value::Finite(true, 0, -398),
+            Value::Finite(false, 1 << 53, 7),
+        ] {
+            assert_eq!(DECIMAL64.decode_bid(DECIMAL64.encode_bid(v)), *v);
+            assert_eq!(DECIMAL64.decode_dpd(DECIMAL64.encode_dpd(v)), *v);
+        }
+    }
+
+    #[test]
+    fn test_decimal128() {
+        let one = Value::Finite(false, 1, 0);
+        assert_eq!(
+            DECIMAL128.encode_bid(&one),
+            0x3040_0000_0000_0000_0000_0000_0000_0001
+        );
+        assert_eq!(
+            DECIMAL128.encode_dpd(&one),
+            0x2208_0000_0000_0000_0000_0000_0000_0001
+        );
+        let max = Value::Finite(false, 10u128.pow(34) - 1, 6111);
+        assert_eq!(
+            DECIMAL128.encode_bid(&max),
+            0x5fff_ed09_bead_87c0_378d_8e63_ffff_ffff
+        );
+        assert_eq!(
+            DECIMAL128.encode_dpd(&max),
+            0x77ff_cff3_fcff_3fcf_f3fc_ff3f_cff3_fcff
+        );
+        for v in &[one, max, Value::Finite(true, 123_456_789, -6176)] {
+            assert_eq!(DECIMAL128.decode_bid(DECIMAL128.encode_bid(v)), *v);
+            assert_eq!(DECIMAL128.decode_dpd(DECIMAL128.encode_dpd(v)), *v);
+        }
+        // Coefficients that start with bits 100 are out of range in decimal128 and read as zero
+        assert_eq!(
+            DECIMAL128.decode_bid(0x6000_0000_0000_0000_0000_0000_0000_0001),
+            Value::Finite(false, 0, -6176)
+        );
+    }
+
+    #[test]
+    fn test_special_values() {
+        assert_eq!(
+            DECIMAL64.encode_bid(&Value::Infinite(false)),
+            0x7800_0000_0000_0000
+        );
+        assert_eq!(
+            DECIMAL64.encode_dpd(&Value::Infinite(true)),
+            0xf800_0000_0000_0000
+        );
+        assert_eq!(DECIMAL64.encode_bid(&Value::NaN), 0x7c00_0000_0000_0000);
+        assert_eq!(
+            DECIMAL128.encode_dpd(&Value::NaN),
+            0x7c00_0000_0000_0000_0000_0000_0000_0000
+        );
+        assert_eq!(
+            DECIMAL64.decode_dpd(0xf800_0000_0000_0000),
+            Value::Infinite(true)
+        );
+        // Signaling NaN and NaN payloads
+        assert_eq!(DECIMAL64.decode_bid(0x7e00_0000_0000_0001), Value::NaN);
+        assert_eq!(
+            DECIMAL128.decode_bid(0xfc00_0000_0000_0000_0000_0000_0000_0000),
+            Value::NaN
+        );
+    }
+}
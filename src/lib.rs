@@ -85,22 +85,182 @@
 //! source code.
 //!
 //! See the [`Number`] trait to find out what the generated types are capable of.
+//!
+//! # Optional Features
+//!
+//! - `arrow` adds conversions between the generated types and the `Decimal128` and `Decimal256` values
+//!   of [Apache Arrow](https://arrow.apache.org), see `to_arrow_decimal128()` and `to_arrow_decimal256()`.
+//! - `bincode` implements `Encode`, `Decode` and `BorrowDecode` from [`bincode`](https://crates.io/crates/bincode) 2
+//!   for the generated types. Numbers are encoded as their `to_le_bytes()` representation.
+//! - `borsh` implements `BorshSerialize` and `BorshDeserialize` from the [`borsh`](https://crates.io/crates/borsh)
+//!   crate for the generated types. Numbers are encoded as their `to_le_bytes()` representation.
+//! - `bytemuck` implements `Zeroable`, `NoUninit` and `CheckedBitPattern` from the
+//!   [`bytemuck`](https://crates.io/crates/bytemuck) crate for the generated types, so they can be cast
+//!   from and to bytes in their `repr(C)` layout. Casting from bytes validates the flags. `Pod` isn't
+//!   implemented, as not every bit pattern is a valid number.
+//! - `num-traits` implements traits from the [`num-traits`](https://crates.io/crates/num-traits)
+//!   crate for the generated types, which lets them work with generic numeric code.
+//! - `rkyv` implements `Archive`, `Serialize` and `Deserialize` from the [`rkyv`](https://crates.io/crates/rkyv)
+//!   crate for the generated types. Numbers are archived as their `to_le_bytes()` representation.
+//! - `serde` implements `Serialize` and `Deserialize` for the generated types. See the [`serde`](serde/index.html)
+//!   module for available representations.
+//! - `serde-arbitrary-precision` additionally allows serializing numbers as JSON numbers without losing precision.
+//! - `zerocopy` derives `IntoBytes`, `Immutable` and `KnownLayout` from the
+//!   [`zerocopy`](https://crates.io/crates/zerocopy) crate for the generated types, and adds `try_ref_from_bytes()`
+//!   and `try_read_from_bytes()` that load numbers from their `repr(C)` layout, validating the flags.
 
+#[cfg(feature = "arrow")]
+#[doc(hidden)]
+pub extern crate arrow_buffer;
+#[cfg(feature = "bincode")]
+#[doc(hidden)]
+pub extern crate bincode2 as bincode_crate;
+#[cfg(feature = "borsh")]
+#[doc(hidden)]
+pub extern crate borsh as borsh_crate;
+#[cfg(feature = "bytemuck")]
+#[doc(hidden)]
+pub extern crate bytemuck as bytemuck_crate;
 extern crate lazy_static;
+#[cfg(feature = "num-traits")]
+#[doc(hidden)]
+pub extern crate num_traits;
+#[cfg(feature = "rkyv")]
+#[doc(hidden)]
+pub extern crate rkyv as rkyv_crate;
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub extern crate serde as serde_crate;
+#[cfg(feature = "serde-arbitrary-precision")]
+extern crate serde_json;
+#[cfg(feature = "zerocopy")]
+#[doc(hidden)]
+pub extern crate zerocopy as zerocopy_crate;
 
+use std::cmp::Ordering;
 use std::fmt::{Debug, Display};
+use std::hash::{Hash, Hasher};
+use std::iter::{Product, Sum};
 use std::ops::{Add, Div, Mul, Neg, Rem, Shr, Sub};
 use std::str::FromStr;
 
 #[doc(hidden)]
 pub use lazy_static::*;
 
+#[cfg(feature = "arrow")]
+#[doc(hidden)]
+pub mod arrow;
+#[doc(hidden)]
+pub mod bcd;
+#[cfg(feature = "bincode")]
+#[doc(hidden)]
+pub mod bincode;
 #[cfg(test)]
 mod binomial;
+#[cfg(feature = "borsh")]
+#[doc(hidden)]
+pub mod borsh;
+#[cfg(feature = "bytemuck")]
+#[doc(hidden)]
+pub mod bytemuck;
 #[doc(hidden)]
 pub mod consts;
+#[doc(hidden)]
+pub mod float;
+pub mod google;
+#[doc(hidden)]
+pub mod ieee;
+#[cfg(feature = "num-traits")]
+mod num;
 mod number;
+#[doc(hidden)]
+pub mod postgres;
 mod prim;
+#[cfg(feature = "rkyv")]
+#[doc(hidden)]
+pub mod rkyv;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "zerocopy")]
+#[doc(hidden)]
+pub mod zerocopy;
+
+#[cfg(not(feature = "arrow"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_arrow {
+    ($name:ident) => {};
+}
+
+#[cfg(not(feature = "bincode"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_bincode {
+    ($name:ident) => {};
+}
+
+#[cfg(not(feature = "borsh"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_borsh {
+    ($name:ident) => {};
+}
+
+#[cfg(not(feature = "bytemuck"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_bytemuck {
+    ($name:ident) => {};
+}
+
+#[cfg(not(feature = "num-traits"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_num_traits {
+    ($name:ident) => {};
+}
+
+#[cfg(not(feature = "rkyv"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_rkyv {
+    ($name:ident) => {};
+}
+
+#[cfg(not(feature = "serde"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_serde {
+    ($name:ident) => {};
+}
+
+#[cfg(not(feature = "zerocopy"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_zerocopy {
+    ($name:ident) => {};
+}
+
+#[cfg(not(feature = "zerocopy"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! derive_zerocopy {
+    ($item:item) => {
+        $item
+    };
+}
+
+#[doc(hidden)]
+pub mod sealed {
+    /// Creates numbers from raw magnitude bytes. Conversions between fdec types rely on it, so it is
+    /// implemented by the generated types only.
+    pub trait FromLeMagnitudeBytes: Sized {
+        /// Creates a number from its magnitude `bytes` in little-endian order, that represent a value with
+        /// the given `scale`. The scale must not be greater than the type scale. Returns `None` if the value
+        /// doesn't fit.
+        fn from_le_magnitude_bytes(neg: bool, bytes: &[u8], scale: usize) -> Option<Self>;
+    }
+}
 
 /// Trait of types that can create values from other types with scaling.
 pub trait WithScale<T> {
@@ -121,16 +281,23 @@ pub trait Number:
     + Mul<Output = Self>
     + Div<Output = Self>
     + Rem<Output = Self>
+    + Sum
+    + Product
     + FromStr
     + From<u8>
     + From<u16>
     + From<u32>
     + From<u64>
+    + From<u128>
+    + From<i128>
     + WithScale<u8>
     + WithScale<u16>
     + WithScale<u32>
     + WithScale<u64>
+    + WithScale<u128>
+    + WithScale<i128>
     + Shr<usize>
+    + sealed::FromLeMagnitudeBytes
 {
     /// Number of decimal places in numbers.
     const SCALE: usize;
@@ -194,6 +361,93 @@ pub trait Number:
 
     /// Returns the number raised to the given integer power.
     fn powi(&self, n: i32) -> Self;
+
+    /// Returns the total ordering between the number and `other`: -Infinity < finite numbers < +Infinity < NaN.
+    /// Unlike `partial_cmp()`, NaN is equal to itself.
+    fn total_cmp(&self, other: &Self) -> Ordering;
+
+    /// Converts the number to another fdec type, rounding it to the scale of that type with the given mode.
+    /// NaN and ±Infinity are converted to the corresponding special values.
+    /// Fails if the value doesn't fit into the target type.
+    fn to_number<N: Number>(&self, mode: RoundingMode) -> Result<N, TryFromNumberError>;
+
+    /// Converts the number to another fdec type without rounding.
+    /// NaN and ±Infinity are converted to the corresponding special values.
+    /// Fails if the value has more fraction digits than the target scale or doesn't fit into the target type.
+    fn to_number_exact<N: Number>(&self) -> Result<N, TryFromNumberError>;
+}
+
+/// Wrapper that makes fdec numbers totally ordered, as defined by [`Number::total_cmp`].
+///
+/// Wrapped numbers implement `Eq`, `Ord` and `Hash`, so they can be sorted, deduplicated and used as keys
+/// in any collections. NaN is equal to itself and greater than any other value.
+///
+/// The generated types implement `Hash`, but not `Eq`, as NaN is not equal to itself. So they can't be
+/// used as `HashMap` or `HashSet` keys directly, wrap them as `Total<Decimal>` instead.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate fdec;
+/// fdec32! { module dec, name Decimal, length 2, scale 4 }
+///
+/// # fn main() {
+/// use fdec::Total;
+/// use dec::*;
+/// use std::collections::{BTreeSet, HashMap};
+///
+/// let mut set = BTreeSet::new();
+/// set.insert(Total(Decimal::nan()));
+/// set.insert(Total(Decimal::from(2)));
+/// set.insert(Total(Decimal::neg_infinity()));
+/// set.insert(Total(Decimal::nan()));
+/// assert_eq!(set.len(), 3);
+/// assert_eq!(set.iter().next(), Some(&Total(Decimal::neg_infinity())));
+///
+/// let mut counts: HashMap<Total<Decimal>, u32> = HashMap::new();
+/// *counts.entry(Total(Decimal::with_scale(15, 1))).or_insert(0) += 1;
+/// *counts.entry(Total(Decimal::with_scale(150, 2))).or_insert(0) += 1;
+/// assert_eq!(counts[&Total(Decimal::with_scale(15, 1))], 2);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Total<N: Number>(pub N);
+
+impl<N: Number> From<N> for Total<N> {
+    #[inline(always)]
+    fn from(n: N) -> Self {
+        Total(n)
+    }
+}
+
+impl<N: Number> PartialEq for Total<N> {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.0.total_cmp(&other.0) == Ordering::Equal
+    }
+}
+
+impl<N: Number> Eq for Total<N> {}
+
+impl<N: Number> PartialOrd for Total<N> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N: Number> Ord for Total<N> {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl<N: Number + Hash> Hash for Total<N> {
+    #[inline(always)]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
 }
 
 /// Represents errors that can be produced when strings are parsed to numbers.
@@ -205,11 +459,95 @@ pub enum ParseNumberError {
     Overflow,
 }
 
-/// Represents errors that can be produces when byte arrays are converted to numbers.
+/// Represents errors that can be produced when numbers are converted to or from byte arrays.
+///
+/// New variants may be added as new binary formats are supported, so matches on this enum must have
+/// a wildcard arm.
 #[derive(PartialEq, Eq, Debug)]
+#[non_exhaustive]
 pub enum FromBytesError {
     /// Flags-byte has invalid value.
     InvalidFlags,
+    /// Buffer is too short to hold the number representation.
+    BufferTooShort,
+    /// Represented value doesn't fit into the numeric type.
+    Overflow,
+    /// Bytes don't form a valid representation of a number.
+    InvalidFormat,
+}
+
+/// Represents errors that can be produced when numbers are converted to or from other numeric types
+/// and formats: primitive integers, other fdec types, IEEE 754 decimals, Arrow decimals, Google `Money`,
+/// packed BCD, two's-complement integers and the packed storage type.
+#[derive(PartialEq, Eq, Debug)]
+pub enum TryFromNumberError {
+    /// Number is NaN or ±Infinity, and the target can't represent it. Returned by conversions to primitive
+    /// integers, Arrow decimals, Google `Money`, packed BCD and two's-complement integers. Conversions to other
+    /// fdec types and IEEE 754 decimals keep special values instead.
+    Special,
+    /// Number has non-zero fraction digits that an exact conversion would lose. Returned by `TryFrom`
+    /// conversions to primitive integers, by `to_number_exact()` and by packed BCD conversions to a smaller scale.
+    Fraction,
+    /// Value doesn't fit into the target type or format, e.g. it has more digits than the Arrow precision
+    /// or the packed BCD field, or its magnitude uses the bits that the packed storage type keeps flags in.
+    /// Returned by conversions in both directions.
+    Overflow,
+    /// Source value doesn't represent a valid number, e.g. Google `Money` units and nanos have different
+    /// signs or nanos are out of range.
+    Invalid,
+}
+
+/// Represents errors that can be produced when numbers are converted from packed BCD.
+#[derive(PartialEq, Eq, Debug)]
+pub enum PackedBcdError {
+    /// Byte array is empty.
+    Empty,
+    /// Nibble at the given position, counting from the most significant one, is not a decimal digit.
+    InvalidDigit(usize),
+    /// Sign nibble has the given value, which is not `C`, `D` or `F`.
+    InvalidSign(u8),
+    /// Represented value has non-zero digits below the scale of the numeric type.
+    Fraction,
+    /// Represented value doesn't fit into the numeric type.
+    Overflow,
+}
+
+/// Defines how a value is rounded when it can't be represented exactly.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RoundingMode {
+    /// Round towards zero.
+    Down,
+    /// Round away from zero.
+    Up,
+    /// Round towards negative infinity.
+    Floor,
+    /// Round towards positive infinity.
+    Ceiling,
+    /// Round towards the nearest neighbor, or away from zero if both neighbors are equidistant.
+    HalfUp,
+    /// Round towards the nearest neighbor, or towards zero if both neighbors are equidistant.
+    HalfDown,
+    /// Round towards the nearest neighbor, or towards the even neighbor if both neighbors are equidistant.
+    HalfEven,
+}
+
+impl RoundingMode {
+    /// Tells if the magnitude of a number must be incremented when the remainder `rem` is discarded.
+    /// `odd` tells if the last kept digit of the magnitude is odd.
+    #[doc(hidden)]
+    pub fn increments(self, neg: bool, odd: bool, rem: Remainder) -> bool {
+        match (self, rem) {
+            (_, Remainder::Zero) | (RoundingMode::Down, _) => false,
+            (RoundingMode::Up, _) => true,
+            (RoundingMode::Floor, _) => neg,
+            (RoundingMode::Ceiling, _) => !neg,
+            (_, Remainder::BelowHalf) => false,
+            (_, Remainder::AboveHalf) => true,
+            (RoundingMode::HalfUp, Remainder::Half) => true,
+            (RoundingMode::HalfDown, Remainder::Half) => false,
+            (RoundingMode::HalfEven, Remainder::Half) => odd,
+        }
+    }
 }
 
 /// Generates a fixed-size fixed-point numeric type that uses `u8`'s as building blocks.
@@ -247,12 +585,88 @@ pub enum FromBytesError {
 /// }
 /// # fn main() {}
 /// ```
+///
+/// A companion type with twice the length and twice the scale can be generated along with the main
+/// type. It holds exact products, returned by `widening_mul()`:
+///
+/// ```
+/// # #[macro_use] extern crate fdec;
+/// fdec8! {              // Use 8-bit units as building blocks
+///     module decimal,   // Name of the module that will contain all the generated code
+///     name Dec,         // Name of the numeric type to be generated
+///     length 7,         // 56-bit number (7 * 8-bit units)
+///     scale 8,          // 8 decimal places
+///     wide module wide, // Name of the module that will contain the wide type
+///     name WideDec      // 112-bit number with 16 decimal places
+/// }
+///
+/// # fn main() {
+/// use fdec::RoundingMode;
+/// use decimal::*;
+///
+/// let a = Dec::with_scale(12345678, 8);
+/// let p = a.widening_mul(a);
+/// assert_eq!(p.to_string(), "0.0152415765279684");
+/// assert_eq!(Dec::from_wide(p, RoundingMode::HalfEven), Ok(Dec::with_scale(1524158, 8)));
+/// # }
+/// ```
+///
+/// A packed storage type can be generated along with the main type. It keeps the flags in the upper
+/// bits of the magnitude, so it takes less memory, and `Option` of it takes no extra memory. It holds
+/// numbers whose magnitudes don't use the upper three bits. The `packed` clause may also follow
+/// the `wide` one to generate both companion types:
+///
+/// ```
+/// # #[macro_use] extern crate fdec;
+/// fdec8! {              // Use 8-bit units as building blocks
+///     module decimal,   // Name of the module that will contain all the generated code
+///     name Dec,         // Name of the numeric type to be generated
+///     length 7,         // 56-bit number (7 * 8-bit units)
+///     scale 8,          // 8 decimal places
+///     packed PackedDec  // Name of the packed type
+/// }
+///
+/// # fn main() {
+/// use std::convert::TryFrom;
+/// use std::mem::size_of;
+/// use decimal::*;
+///
+/// assert_eq!(size_of::<Dec>(), 8);
+/// assert_eq!(size_of::<Option<PackedDec>>(), 7);
+/// let c = PackedDec::try_from(Dec::with_scale(1547, 2)).unwrap();
+/// assert_eq!(c.get(), Dec::with_scale(1547, 2));
+/// # }
+/// ```
 #[macro_export]
 macro_rules! fdec8 {
     (module $modname:ident, name $name:ident, length $mlen:expr) => {
         fdec8!(module $modname, name $name, length $mlen, scale 0);
     };
     (module $modname:ident, name $name:ident, length $mlen:expr, scale $scale:expr) => {
+        fdec8!(@module $modname, name $name, length $mlen, scale $scale, {});
+    };
+    (module $modname:ident, name $name:ident, length $mlen:expr, scale $scale:expr,
+     wide module $wmodname:ident, name $wname:ident) => {
+        fdec8!(@module $modname, name $name, length $mlen, scale $scale, {
+            impl_widening!($name, super::$wmodname::$wname);
+        });
+        fdec8!(module $wmodname, name $wname, length 2 * $mlen, scale 2 * $scale);
+    };
+    (module $modname:ident, name $name:ident, length $mlen:expr, scale $scale:expr,
+     packed $pname:ident) => {
+        fdec8!(@module $modname, name $name, length $mlen, scale $scale, {
+            impl_packed!($name, $pname, ::std::num::NonZeroU8);
+        });
+    };
+    (module $modname:ident, name $name:ident, length $mlen:expr, scale $scale:expr,
+     wide module $wmodname:ident, name $wname:ident, packed $pname:ident) => {
+        fdec8!(@module $modname, name $name, length $mlen, scale $scale, {
+            impl_widening!($name, super::$wmodname::$wname);
+            impl_packed!($name, $pname, ::std::num::NonZeroU8);
+        });
+        fdec8!(module $wmodname, name $wname, length 2 * $mlen, scale 2 * $scale);
+    };
+    (@module $modname:ident, name $name:ident, length $mlen:expr, scale $scale:expr, { $($extra:tt)* }) => {
         /// Module that contains the generated numeric type
         #[allow(non_upper_case_globals)]
         #[macro_use]
@@ -264,6 +678,9 @@ macro_rules! fdec8 {
             impl_big_primitive_interop!($name, u16, i16, i16);
             impl_big_primitive_interop!($name, u32, i32, i32);
             impl_big_primitive_interop!($name, u64, i64, i64);
+            impl_big_primitive_interop!($name, u128, i128, i128);
+            impl_big_primitive_interop!($name, usize, isize, isize);
+            $($extra)*
         }
     };
 }
@@ -303,12 +720,88 @@ macro_rules! fdec8 {
 /// }
 /// # fn main() {}
 /// ```
+///
+/// A companion type with twice the length and twice the scale can be generated along with the main
+/// type. It holds exact products, returned by `widening_mul()`:
+///
+/// ```
+/// # #[macro_use] extern crate fdec;
+/// fdec16! {             // Use 16-bit units as building blocks
+///     module decimal,   // Name of the module that will contain all the generated code
+///     name Dec,         // Name of the numeric type to be generated
+///     length 6,         // 96-bit number (6 * 16-bit units)
+///     scale 8,          // 8 decimal places
+///     wide module wide, // Name of the module that will contain the wide type
+///     name WideDec      // 192-bit number with 16 decimal places
+/// }
+///
+/// # fn main() {
+/// use fdec::RoundingMode;
+/// use decimal::*;
+///
+/// let a = Dec::with_scale(12345678, 8);
+/// let p = a.widening_mul(a);
+/// assert_eq!(p.to_string(), "0.0152415765279684");
+/// assert_eq!(Dec::from_wide(p, RoundingMode::HalfEven), Ok(Dec::with_scale(1524158, 8)));
+/// # }
+/// ```
+///
+/// A packed storage type can be generated along with the main type. It keeps the flags in the upper
+/// bits of the magnitude, so it takes less memory, and `Option` of it takes no extra memory. It holds
+/// numbers whose magnitudes don't use the upper three bits. The `packed` clause may also follow
+/// the `wide` one to generate both companion types:
+///
+/// ```
+/// # #[macro_use] extern crate fdec;
+/// fdec16! {             // Use 16-bit units as building blocks
+///     module decimal,   // Name of the module that will contain all the generated code
+///     name Dec,         // Name of the numeric type to be generated
+///     length 3,         // 48-bit number (3 * 16-bit units)
+///     scale 4,          // 4 decimal places
+///     packed PackedDec  // Name of the packed type
+/// }
+///
+/// # fn main() {
+/// use std::convert::TryFrom;
+/// use std::mem::size_of;
+/// use decimal::*;
+///
+/// assert_eq!(size_of::<Dec>(), 8);
+/// assert_eq!(size_of::<Option<PackedDec>>(), 6);
+/// let c = PackedDec::try_from(Dec::with_scale(1547, 2)).unwrap();
+/// assert_eq!(c.get(), Dec::with_scale(1547, 2));
+/// # }
+/// ```
 #[macro_export]
 macro_rules! fdec16 {
     (module $modname:ident, name $name:ident, length $mlen:expr) => {
         fdec16!(module $modname, name $name, length $mlen, scale 0);
     };
     (module $modname:ident, name $name:ident, length $mlen:expr, scale $scale:expr) => {
+        fdec16!(@module $modname, name $name, length $mlen, scale $scale, {});
+    };
+    (module $modname:ident, name $name:ident, length $mlen:expr, scale $scale:expr,
+     wide module $wmodname:ident, name $wname:ident) => {
+        fdec16!(@module $modname, name $name, length $mlen, scale $scale, {
+            impl_widening!($name, super::$wmodname::$wname);
+        });
+        fdec16!(module $wmodname, name $wname, length 2 * $mlen, scale 2 * $scale);
+    };
+    (module $modname:ident, name $name:ident, length $mlen:expr, scale $scale:expr,
+     packed $pname:ident) => {
+        fdec16!(@module $modname, name $name, length $mlen, scale $scale, {
+            impl_packed!($name, $pname, ::std::num::NonZeroU16);
+        });
+    };
+    (module $modname:ident, name $name:ident, length $mlen:expr, scale $scale:expr,
+     wide module $wmodname:ident, name $wname:ident, packed $pname:ident) => {
+        fdec16!(@module $modname, name $name, length $mlen, scale $scale, {
+            impl_widening!($name, super::$wmodname::$wname);
+            impl_packed!($name, $pname, ::std::num::NonZeroU16);
+        });
+        fdec16!(module $wmodname, name $wname, length 2 * $mlen, scale 2 * $scale);
+    };
+    (@module $modname:ident, name $name:ident, length $mlen:expr, scale $scale:expr, { $($extra:tt)* }) => {
         /// Module that contains the generated numeric type
         #[allow(non_upper_case_globals)]
         #[macro_use]
@@ -320,6 +813,12 @@ macro_rules! fdec16 {
             impl_unit_primitive_interop!($name, u16, i16, i16);
             impl_big_primitive_interop!($name, u32, i32, i32);
             impl_big_primitive_interop!($name, u64, i64, i64);
+            impl_big_primitive_interop!($name, u128, i128, i128);
+            #[cfg(target_pointer_width = "16")]
+            impl_unit_primitive_interop!($name, usize, isize, isize);
+            #[cfg(not(target_pointer_width = "16"))]
+            impl_big_primitive_interop!($name, usize, isize, isize);
+            $($extra)*
         }
     };
 }
@@ -359,12 +858,88 @@ macro_rules! fdec16 {
 /// }
 /// # fn main() {}
 /// ```
+///
+/// A companion type with twice the length and twice the scale can be generated along with the main
+/// type. It holds exact products, returned by `widening_mul()`:
+///
+/// ```
+/// # #[macro_use] extern crate fdec;
+/// fdec32! {             // Use 32-bit units as building blocks
+///     module decimal,   // Name of the module that will contain all the generated code
+///     name Dec,         // Name of the numeric type to be generated
+///     length 5,         // 160-bit number (5 * 32-bit units)
+///     scale 8,          // 8 decimal places
+///     wide module wide, // Name of the module that will contain the wide type
+///     name WideDec      // 320-bit number with 16 decimal places
+/// }
+///
+/// # fn main() {
+/// use fdec::RoundingMode;
+/// use decimal::*;
+///
+/// let a = Dec::with_scale(12345678, 8);
+/// let p = a.widening_mul(a);
+/// assert_eq!(p.to_string(), "0.0152415765279684");
+/// assert_eq!(Dec::from_wide(p, RoundingMode::HalfEven), Ok(Dec::with_scale(1524158, 8)));
+/// # }
+/// ```
+///
+/// A packed storage type can be generated along with the main type. It keeps the flags in the upper
+/// bits of the magnitude, so it takes less memory, and `Option` of it takes no extra memory. It holds
+/// numbers whose magnitudes don't use the upper three bits. The `packed` clause may also follow
+/// the `wide` one to generate both companion types:
+///
+/// ```
+/// # #[macro_use] extern crate fdec;
+/// fdec32! {             // Use 32-bit units as building blocks
+///     module decimal,   // Name of the module that will contain all the generated code
+///     name Dec,         // Name of the numeric type to be generated
+///     length 3,         // 96-bit number (3 * 32-bit units)
+///     scale 6,          // 6 decimal places
+///     packed PackedDec  // Name of the packed type
+/// }
+///
+/// # fn main() {
+/// use std::convert::TryFrom;
+/// use std::mem::size_of;
+/// use decimal::*;
+///
+/// assert_eq!(size_of::<Dec>(), 16);
+/// assert_eq!(size_of::<Option<PackedDec>>(), 12);
+/// let c = PackedDec::try_from(Dec::with_scale(1547, 2)).unwrap();
+/// assert_eq!(c.get(), Dec::with_scale(1547, 2));
+/// # }
+/// ```
 #[macro_export]
 macro_rules! fdec32 {
     (module $modname:ident, name $name:ident, length $mlen:expr) => {
         fdec32!(module $modname, name $name, length $mlen, scale 0);
     };
     (module $modname:ident, name $name:ident, length $mlen:expr, scale $scale:expr) => {
+        fdec32!(@module $modname, name $name, length $mlen, scale $scale, {});
+    };
+    (module $modname:ident, name $name:ident, length $mlen:expr, scale $scale:expr,
+     wide module $wmodname:ident, name $wname:ident) => {
+        fdec32!(@module $modname, name $name, length $mlen, scale $scale, {
+            impl_widening!($name, super::$wmodname::$wname);
+        });
+        fdec32!(module $wmodname, name $wname, length 2 * $mlen, scale 2 * $scale);
+    };
+    (module $modname:ident, name $name:ident, length $mlen:expr, scale $scale:expr,
+     packed $pname:ident) => {
+        fdec32!(@module $modname, name $name, length $mlen, scale $scale, {
+            impl_packed!($name, $pname, ::std::num::NonZeroU32);
+        });
+    };
+    (module $modname:ident, name $name:ident, length $mlen:expr, scale $scale:expr,
+     wide module $wmodname:ident, name $wname:ident, packed $pname:ident) => {
+        fdec32!(@module $modname, name $name, length $mlen, scale $scale, {
+            impl_widening!($name, super::$wmodname::$wname);
+            impl_packed!($name, $pname, ::std::num::NonZeroU32);
+        });
+        fdec32!(module $wmodname, name $wname, length 2 * $mlen, scale 2 * $scale);
+    };
+    (@module $modname:ident, name $name:ident, length $mlen:expr, scale $scale:expr, { $($extra:tt)* }) => {
         /// Module that contains the generated numeric type
         #[allow(non_upper_case_globals)]
         #[macro_use]
@@ -376,6 +951,12 @@ macro_rules! fdec32 {
             impl_unit_primitive_interop!($name, u16, i16, i16);
             impl_unit_primitive_interop!($name, u32, i32, i32);
             impl_big_primitive_interop!($name, u64, i64, i64);
+            impl_big_primitive_interop!($name, u128, i128, i128);
+            #[cfg(any(target_pointer_width = "16", target_pointer_width = "32"))]
+            impl_unit_primitive_interop!($name, usize, isize, isize);
+            #[cfg(not(any(target_pointer_width = "16", target_pointer_width = "32")))]
+            impl_big_primitive_interop!($name, usize, isize, isize);
+            $($extra)*
         }
     };
 }
@@ -415,12 +996,88 @@ macro_rules! fdec32 {
 /// }
 /// # fn main() {}
 /// ```
+///
+/// A companion type with twice the length and twice the scale can be generated along with the main
+/// type. It holds exact products, returned by `widening_mul()`:
+///
+/// ```
+/// # #[macro_use] extern crate fdec;
+/// fdec64! {             // Use 64-bit units as building blocks
+///     module decimal,   // Name of the module that will contain all the generated code
+///     name Dec,         // Name of the numeric type to be generated
+///     length 3,         // 192-bit number (3 * 64-bit units)
+///     scale 8,          // 8 decimal places
+///     wide module wide, // Name of the module that will contain the wide type
+///     name WideDec      // 384-bit number with 16 decimal places
+/// }
+///
+/// # fn main() {
+/// use fdec::RoundingMode;
+/// use decimal::*;
+///
+/// let a = Dec::with_scale(12345678, 8);
+/// let p = a.widening_mul(a);
+/// assert_eq!(p.to_string(), "0.0152415765279684");
+/// assert_eq!(Dec::from_wide(p, RoundingMode::HalfEven), Ok(Dec::with_scale(1524158, 8)));
+/// # }
+/// ```
+///
+/// A packed storage type can be generated along with the main type. It keeps the flags in the upper
+/// bits of the magnitude, so it takes less memory, and `Option` of it takes no extra memory. It holds
+/// numbers whose magnitudes don't use the upper three bits. The `packed` clause may also follow
+/// the `wide` one to generate both companion types:
+///
+/// ```
+/// # #[macro_use] extern crate fdec;
+/// fdec64! {             // Use 64-bit units as building blocks
+///     module decimal,   // Name of the module that will contain all the generated code
+///     name Dec,         // Name of the numeric type to be generated
+///     length 1,         // 64-bit number (1 * 64-bit units)
+///     scale 8,          // 8 decimal places
+///     packed PackedDec  // Name of the packed type
+/// }
+///
+/// # fn main() {
+/// use std::convert::TryFrom;
+/// use std::mem::size_of;
+/// use decimal::*;
+///
+/// assert_eq!(size_of::<Dec>(), 16);
+/// assert_eq!(size_of::<Option<PackedDec>>(), 8);
+/// let c = PackedDec::try_from(Dec::with_scale(1547, 2)).unwrap();
+/// assert_eq!(c.get(), Dec::with_scale(1547, 2));
+/// # }
+/// ```
 #[macro_export]
 macro_rules! fdec64 {
     (module $modname:ident, name $name:ident, length $mlen:expr) => {
         fdec64!(module $modname, name $name, length $mlen, scale 0);
     };
     (module $modname:ident, name $name:ident, length $mlen:expr, scale $scale:expr) => {
+        fdec64!(@module $modname, name $name, length $mlen, scale $scale, {});
+    };
+    (module $modname:ident, name $name:ident, length $mlen:expr, scale $scale:expr,
+     wide module $wmodname:ident, name $wname:ident) => {
+        fdec64!(@module $modname, name $name, length $mlen, scale $scale, {
+            impl_widening!($name, super::$wmodname::$wname);
+        });
+        fdec64!(module $wmodname, name $wname, length 2 * $mlen, scale 2 * $scale);
+    };
+    (module $modname:ident, name $name:ident, length $mlen:expr, scale $scale:expr,
+     packed $pname:ident) => {
+        fdec64!(@module $modname, name $name, length $mlen, scale $scale, {
+            impl_packed!($name, $pname, ::std::num::NonZeroU64);
+        });
+    };
+    (module $modname:ident, name $name:ident, length $mlen:expr, scale $scale:expr,
+     wide module $wmodname:ident, name $wname:ident, packed $pname:ident) => {
+        fdec64!(@module $modname, name $name, length $mlen, scale $scale, {
+            impl_widening!($name, super::$wmodname::$wname);
+            impl_packed!($name, $pname, ::std::num::NonZeroU64);
+        });
+        fdec64!(module $wmodname, name $wname, length 2 * $mlen, scale 2 * $scale);
+    };
+    (@module $modname:ident, name $name:ident, length $mlen:expr, scale $scale:expr, { $($extra:tt)* }) => {
         /// Module that contains the generated numeric type
         #[allow(non_upper_case_globals)]
         #[macro_use]
@@ -432,10 +1089,59 @@ macro_rules! fdec64 {
             impl_unit_primitive_interop!($name, u16, i16, i16);
             impl_unit_primitive_interop!($name, u32, i32, i32);
             impl_unit_primitive_interop!($name, u64, i64, i64);
+            impl_big_primitive_interop!($name, u128, i128, i128);
+            #[cfg(any(target_pointer_width = "16", target_pointer_width = "32", target_pointer_width = "64"))]
+            impl_unit_primitive_interop!($name, usize, isize, isize);
+            #[cfg(not(any(target_pointer_width = "16", target_pointer_width = "32", target_pointer_width = "64")))]
+            impl_big_primitive_interop!($name, usize, isize, isize);
+            $($extra)*
         }
     };
 }
 
+/// Implements `TryFrom` conversions between fdec types, generated in the same crate.
+///
+/// The conversions are exact: they fail with `TryFromNumberError::Fraction` if the value has more
+/// fraction digits than the target type can hold, and with `TryFromNumberError::Overflow` if the value
+/// doesn't fit into the target type. Use [`Number::to_number`] to convert values with rounding.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate fdec;
+/// fdec64! { module price, name Price, length 1, scale 8 }
+/// fdec64! { module notional, name Notional, length 4, scale 18 }
+///
+/// fdec_convert!(price::Price => notional::Notional, notional::Notional => price::Price);
+///
+/// # fn main() {
+/// use fdec::WithScale;
+/// use std::convert::TryFrom;
+/// use price::Price;
+/// use notional::Notional;
+///
+/// let p = Price::with_scale(12345, 2);
+/// let n = Notional::try_from(p).unwrap();
+/// assert_eq!(n, Notional::with_scale(12345, 2));
+/// assert_eq!(Price::try_from(n), Ok(p));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! fdec_convert {
+    ($($from:ty => $to:ty),+ $(,)*) => {
+        $(
+            impl ::std::convert::TryFrom<$from> for $to {
+                type Error = $crate::TryFromNumberError;
+
+                #[inline(always)]
+                fn try_from(v: $from) -> Result<Self, Self::Error> {
+                    $crate::Number::to_number_exact(&v)
+                }
+            }
+        )+
+    };
+}
+
 /// Basic information about the string to be parsed
 #[doc(hidden)]
 pub struct StrInfo<'a> {
@@ -465,3 +1171,27 @@ impl<'a> StrInfo<'a> {
         self.point
     }
 }
+
+/// Part of a number that is discarded when the number is rounded,
+/// compared to the half of the last kept digit.
+#[doc(hidden)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Remainder {
+    Zero,
+    BelowHalf,
+    Half,
+    AboveHalf,
+}
+
+impl Remainder {
+    /// Returns the remainder that starts with the decimal `digit`. `sticky` tells if there are
+    /// non-zero digits after it.
+    pub fn from_digit(digit: u8, sticky: bool) -> Remainder {
+        match digit {
+            0 if !sticky => Remainder::Zero,
+            0..=4 => Remainder::BelowHalf,
+            5 if !sticky => Remainder::Half,
+            _ => Remainder::AboveHalf,
+        }
+    }
+}
@@ -0,0 +1,247 @@
+//! Integration with the `num-traits` crate.
+
+/// Generates implementations of the `num-traits` traits for an fdec type.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_num_traits {
+    ($name:ident) => {
+        impl $crate::num_traits::Zero for $name {
+            #[inline]
+            fn zero() -> Self {
+                <$name as Number>::zero()
+            }
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                Number::is_zero(self)
+            }
+        }
+
+        impl $crate::num_traits::One for $name {
+            #[inline]
+            fn one() -> Self {
+                <$name as Number>::one()
+            }
+        }
+
+        impl $crate::num_traits::Num for $name {
+            type FromStrRadixErr = ParseNumberError;
+
+            /// Parses a number in the given radix. Radix 10 accepts all the formats supported
+            /// by `FromStr`, other radixes accept integers only.
+            fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+                if radix == 10 {
+                    return $name::from_str(s);
+                }
+                assert!(
+                    (2..=36).contains(&radix),
+                    "radix must lie in the range 2..=36"
+                );
+                let (neg, digits) = match s.as_bytes().first() {
+                    Some(b'-') => (true, &s[1..]),
+                    Some(b'+') => (false, &s[1..]),
+                    _ => (false, s),
+                };
+                if digits.is_empty() {
+                    return Err(ParseNumberError::InvalidFormat);
+                }
+                let mut res = <$name as Number>::zero();
+                for c in digits.chars() {
+                    let digit = c.to_digit(radix).ok_or(ParseNumberError::InvalidFormat)?;
+                    res = res * radix + digit;
+                    if res.is_infinite() {
+                        return Err(ParseNumberError::Overflow);
+                    }
+                }
+                Ok(if neg { -res } else { res })
+            }
+        }
+
+        impl $crate::num_traits::Signed for $name {
+            #[inline]
+            fn abs(&self) -> Self {
+                Number::abs(self)
+            }
+
+            fn abs_sub(&self, other: &Self) -> Self {
+                if *self <= *other {
+                    <$name as Number>::zero()
+                } else {
+                    *self - *other
+                }
+            }
+
+            fn signum(&self) -> Self {
+                if self.is_nan() {
+                    *self
+                } else if Number::is_zero(self) {
+                    <$name as Number>::zero()
+                } else if self.is_sign_negative() {
+                    -<$name as Number>::one()
+                } else {
+                    <$name as Number>::one()
+                }
+            }
+
+            #[inline]
+            fn is_positive(&self) -> bool {
+                self.is_sign_positive() && !self.is_nan() && !Number::is_zero(self)
+            }
+
+            #[inline]
+            fn is_negative(&self) -> bool {
+                self.is_sign_negative() && !self.is_nan() && !Number::is_zero(self)
+            }
+        }
+
+        impl $crate::num_traits::Bounded for $name {
+            #[inline]
+            fn min_value() -> Self {
+                $name::MIN
+            }
+
+            #[inline]
+            fn max_value() -> Self {
+                $name::MAX
+            }
+        }
+
+        impl $crate::num_traits::ToPrimitive for $name {
+            #[inline]
+            fn to_i64(&self) -> Option<i64> {
+                self.to_i64_truncated().ok()
+            }
+
+            #[inline]
+            fn to_u64(&self) -> Option<u64> {
+                self.to_u64_truncated().ok()
+            }
+
+            #[inline]
+            fn to_i128(&self) -> Option<i128> {
+                self.to_i128_truncated().ok()
+            }
+
+            #[inline]
+            fn to_u128(&self) -> Option<u128> {
+                self.to_u128_truncated().ok()
+            }
+
+            #[inline]
+            fn to_f32(&self) -> Option<f32> {
+                Some($name::to_f32(self))
+            }
+
+            #[inline]
+            fn to_f64(&self) -> Option<f64> {
+                Some($name::to_f64(self))
+            }
+        }
+
+        impl $crate::num_traits::FromPrimitive for $name {
+            #[inline]
+            fn from_i64(n: i64) -> Option<Self> {
+                Some($name::from(n)).filter(|v| !v.is_infinite())
+            }
+
+            #[inline]
+            fn from_u64(n: u64) -> Option<Self> {
+                Some($name::from(n)).filter(|v| !v.is_infinite())
+            }
+
+            #[inline]
+            fn from_i128(n: i128) -> Option<Self> {
+                Some($name::from(n)).filter(|v| !v.is_infinite())
+            }
+
+            #[inline]
+            fn from_u128(n: u128) -> Option<Self> {
+                Some($name::from(n)).filter(|v| !v.is_infinite())
+            }
+
+            /// Converts the value exactly, rounding half-way cases to even. Returns `None` if
+            /// a finite value doesn't fit into the type.
+            #[inline]
+            fn from_f32(n: f32) -> Option<Self> {
+                Some($name::from(n)).filter(|v| !v.is_infinite() || n.is_infinite())
+            }
+
+            /// Converts the value exactly, rounding half-way cases to even. Returns `None` if
+            /// a finite value doesn't fit into the type.
+            #[inline]
+            fn from_f64(n: f64) -> Option<Self> {
+                Some($name::from(n)).filter(|v| !v.is_infinite() || n.is_infinite())
+            }
+        }
+
+        impl $crate::num_traits::NumCast for $name {
+            /// Converts integers exactly and other values through `f64`.
+            fn from<T: $crate::num_traits::ToPrimitive>(n: T) -> Option<Self> {
+                use $crate::num_traits::FromPrimitive;
+                let f = n.to_f64();
+                if let Some(i) = n.to_i128() {
+                    if f == Some(i as f64) {
+                        return $name::from_i128(i);
+                    }
+                }
+                if let Some(u) = n.to_u128() {
+                    if f == Some(u as f64) {
+                        return $name::from_u128(u);
+                    }
+                }
+                f.and_then($name::from_f64)
+            }
+        }
+
+        impl $crate::num_traits::CheckedAdd for $name {
+            /// Adds two numbers, returning `None` if the result is not a finite number.
+            #[inline]
+            fn checked_add(&self, v: &Self) -> Option<Self> {
+                Some(*self + *v).filter(|r| !r.is_special())
+            }
+        }
+
+        impl $crate::num_traits::CheckedSub for $name {
+            /// Subtracts two numbers, returning `None` if the result is not a finite number.
+            #[inline]
+            fn checked_sub(&self, v: &Self) -> Option<Self> {
+                Some(*self - *v).filter(|r| !r.is_special())
+            }
+        }
+
+        impl $crate::num_traits::CheckedMul for $name {
+            /// Multiplies two numbers, returning `None` if the result is not a finite number.
+            #[inline]
+            fn checked_mul(&self, v: &Self) -> Option<Self> {
+                Some(*self * *v).filter(|r| !r.is_special())
+            }
+        }
+
+        impl $crate::num_traits::CheckedDiv for $name {
+            /// Divides two numbers, returning `None` if the result is not a finite number,
+            /// which includes division by zero.
+            #[inline]
+            fn checked_div(&self, v: &Self) -> Option<Self> {
+                Some(*self / *v).filter(|r| !r.is_special())
+            }
+        }
+
+        impl $crate::num_traits::Pow<i32> for $name {
+            type Output = $name;
+
+            #[inline]
+            fn pow(self, n: i32) -> Self::Output {
+                self.powi(n)
+            }
+        }
+
+        impl<'a> $crate::num_traits::Pow<i32> for &'a $name {
+            type Output = $name;
+
+            #[inline]
+            fn pow(self, n: i32) -> Self::Output {
+                self.powi(n)
+            }
+        }
+    };
+}
@@ -22,12 +22,18 @@ macro_rules! fdec {
      module $modname:ident, name $name:ident, length $mlen:expr, scale $scale:expr) => {
 
         use std::cmp::{PartialEq, PartialOrd, Ordering};
+        use std::num::FpCategory;
         use std::fmt::{self, Debug, Display, Formatter};
+        use std::hash::{Hash, Hasher};
+        use std::io;
+        use std::iter::{Product, Sum};
         use std::ops::*;
         use std::str::FromStr;
 
         #[doc(hidden)]
-        pub use $crate::{Number, WithScale, ParseNumberError, FromBytesError, StrInfo};
+        pub use $crate::{Number, WithScale, ParseNumberError, FromBytesError, TryFromNumberError, PackedBcdError, RoundingMode, Total, StrInfo};
+        use $crate::Remainder;
+        use $crate::sealed::FromLeMagnitudeBytes;
 
         const M_LENGTH: usize = $mlen;                              // Length of the array (in units) that holds the number data
 
@@ -49,6 +55,30 @@ macro_rules! fdec {
         const FLAGS_NO: Flags = 0;
         const FLAGS_SPECIAL: Flags = FLAG_NAN | FLAG_INFINITY;
 
+        // Leading bytes of the sortable representation, in the order of values they denote
+        const SORTABLE_NEG_INFINITY: u8 = 0;
+        const SORTABLE_NEGATIVE: u8 = 1;
+        const SORTABLE_POSITIVE: u8 = 2;                            // Includes zero
+        const SORTABLE_INFINITY: u8 = 3;
+        const SORTABLE_NAN: u8 = 4;
+
+        // Header of the compact representation holds flags in the lower bits and the length of the magnitude
+        // in the higher bits. Longer magnitudes have the extended length marker in the header, and the rest of
+        // their length follows it as a base-128 varint.
+        const MAGNITUDE_BYTES: usize = M_LENGTH * UNIT_BYTES;
+        const COMPACT_FLAGS_BITS: usize = 3;
+        const COMPACT_FLAGS_MASK: u8 = (1 << COMPACT_FLAGS_BITS) - 1;
+        const COMPACT_EXTENDED_LEN: usize = 0xff >> COMPACT_FLAGS_BITS;
+        const COMPACT_EXTRA_LEN_BYTES: usize = {
+            let mut extra = MAGNITUDE_BYTES.saturating_sub(COMPACT_EXTENDED_LEN);
+            let mut n = if MAGNITUDE_BYTES < COMPACT_EXTENDED_LEN { 0 } else { 1 };
+            while extra >= 0x80 {
+                extra >>= 7;
+                n += 1;
+            }
+            n
+        };
+
         const ZERO_UNIT: Unit = 0;
         const BIG_M_LENGTH: usize = 2 * M_LENGTH;
         const BIG_ONE: BigUnit = 1 << UNIT_BITS;
@@ -125,11 +155,17 @@ macro_rules! fdec {
             }
         }
 
-        /// A fixed-size fixed-point numeric type.
-        #[derive(Copy, Clone)]
-        pub struct $name {
-            flags: Flags,
-            magnitude: [Unit; M_LENGTH],    // Number magnitude in little-endian order
+        derive_zerocopy! {
+            /// A fixed-size fixed-point numeric type.
+            ///
+            /// The type has a stable `repr(C)` layout: the magnitude units in little-endian order followed
+            /// by the flags unit, with no padding between them.
+            #[derive(Copy, Clone)]
+            #[repr(C)]
+            pub struct $name {
+                magnitude: [Unit; M_LENGTH],    // Number magnitude in little-endian order
+                flags: Flags,
+            }
         }
 
         impl Number for $name {
@@ -294,6 +330,49 @@ macro_rules! fdec {
                 }
                 res
             }
+
+            fn total_cmp(&self, other: &$name) -> Ordering {
+                match (self.is_nan(), other.is_nan()) {
+                    (true, true) => Ordering::Equal,
+                    (true, false) => Ordering::Greater,
+                    (false, true) => Ordering::Less,
+                    (false, false) => self.partial_cmp(other).unwrap_or(Ordering::Equal),
+                }
+            }
+
+            #[inline(always)]
+            fn to_number<N: Number>(&self, mode: RoundingMode) -> Result<N, TryFromNumberError> {
+                self.convert(Some(mode))
+            }
+
+            #[inline(always)]
+            fn to_number_exact<N: Number>(&self) -> Result<N, TryFromNumberError> {
+                self.convert(None)
+            }
+        }
+
+        impl FromLeMagnitudeBytes for $name {
+            fn from_le_magnitude_bytes(neg: bool, bytes: &[u8], scale: usize) -> Option<Self> {
+                debug_assert!(scale <= $name::SCALE);
+                let mut mag = [0; M_LENGTH];
+                for (i, chunk) in bytes.chunks(UNIT_BYTES).enumerate() {
+                    let mut unit_bytes: [u8; UNIT_BYTES] = [0; UNIT_BYTES];
+                    unit_bytes[..chunk.len()].copy_from_slice(chunk);
+                    let u = Unit::from_le_bytes(unit_bytes);
+                    if u != 0 {
+                        if i >= M_LENGTH {
+                            return None;
+                        }
+                        mag[i] = u;
+                    }
+                }
+                let mut num = $name::from_le_units(neg, mag);
+                if num.move_point_right($name::SCALE - scale) {
+                    None
+                } else {
+                    Some(num)
+                }
+            }
         }
 
         // Result of converting a byte to flags.
@@ -351,8 +430,124 @@ macro_rules! fdec {
                 $name::from_le_units(neg, le_magnitude)
             }
 
+            /// Creates a number from its sign and magnitude (in little-endian units order). The magnitude is
+            /// the value of the number multiplied by `10^SCALE`, so `from_parts(false, [1, 0, ...])` creates `ulp()`.
+            #[inline]
+            pub fn from_parts(neg: bool, magnitude: [Unit; M_LENGTH]) -> Self {
+                $name::from_le_units(neg, magnitude)
+            }
+
+            /// Returns the sign and the magnitude (in little-endian units order) of the number. The magnitude is
+            /// the value of the number multiplied by `10^SCALE`.
+            ///
+            /// The magnitude of NaN and ±Infinity is zero, so special values should be checked with
+            /// `is_special()` first.
+            #[inline]
+            pub fn to_parts(&self) -> (bool, [Unit; M_LENGTH]) {
+                (self.is_sign_negative(), self.magnitude)
+            }
+
+            /// Returns the magnitude of the number in little-endian units order without copying it.
+            /// The magnitude is the value of the number multiplied by `10^SCALE`.
+            #[inline]
+            pub fn as_le_units(&self) -> &[Unit] {
+                &self.magnitude
+            }
+
+            /// Creates a number from its value multiplied by `10^SCALE`, so `from_scaled_i128(1)` creates `ulp()`.
+            /// Values that don't fit into the type are converted to ±Infinity.
+            pub fn from_scaled_i128(v: i128) -> Self {
+                let neg = v < 0;
+                $name::from_le_magnitude_bytes(neg, &v.unsigned_abs().to_le_bytes(), $name::SCALE)
+                    .unwrap_or(if neg { $name::NEG_INFINITY } else { $name::INFINITY })
+            }
+
+            /// Returns the value of the number multiplied by `10^SCALE`, so `ulp().to_scaled_i128()` returns `1`.
+            ///
+            /// Fails with `TryFromNumberError::Overflow` if the result doesn't fit into `i128`, and with
+            /// `TryFromNumberError::Special` if the number is NaN or ±Infinity.
+            pub fn to_scaled_i128(&self) -> Result<i128, TryFromNumberError> {
+                if self.is_special() {
+                    return Err(TryFromNumberError::Special);
+                }
+                let m = magnitude_to_u128(&self.magnitude).ok_or(TryFromNumberError::Overflow)?;
+                if self.is_sign_negative() {
+                    if m > i128::MIN.unsigned_abs() {
+                        return Err(TryFromNumberError::Overflow);
+                    }
+                    Ok((m as i128).wrapping_neg())
+                } else {
+                    if m > i128::MAX as u128 {
+                        return Err(TryFromNumberError::Overflow);
+                    }
+                    Ok(m as i128)
+                }
+            }
+
+            /// Length of the byte array representation of numbers.
+            pub const BYTE_LEN: usize = BYTE_ARRAY_LEN;
+
             /// Creates a number from its representation as a byte array in big-endian order.
+            #[inline]
             pub fn from_be_bytes(bytes: &[u8; BYTE_ARRAY_LEN]) -> Result<Self, FromBytesError> {
+                $name::read_be(bytes)
+            }
+
+            /// Creates a number from its representation as a byte array in little-endian order.
+            #[inline]
+            pub fn from_le_bytes(bytes: &[u8; BYTE_ARRAY_LEN]) -> Result<Self, FromBytesError> {
+                $name::read_le(bytes)
+            }
+
+            /// Creates a number from its representation as a byte array in native order.
+            ///
+            /// As the target platform’s native endianness is used, portable code should use `from_be_bytes()`
+            /// or `from_le_bytes()`, as appropriate, instead.
+            #[inline(always)]
+            pub fn from_ne_bytes(bytes: &[u8; BYTE_ARRAY_LEN]) -> Result<Self, FromBytesError> {
+                #[cfg(target_endian = "little")]
+                {
+                    $name::from_le_bytes(bytes)
+                }
+                #[cfg(target_endian = "big")]
+                {
+                    $name::from_be_bytes(bytes)
+                }
+            }
+
+            /// Creates a number from its representation in big-endian order, that occupies the first
+            /// `BYTE_LEN` bytes of `buf`. Returns the number and the number of bytes read.
+            pub fn read_be_bytes(buf: &[u8]) -> Result<(Self, usize), FromBytesError> {
+                let bytes = buf.get(..BYTE_ARRAY_LEN).ok_or(FromBytesError::BufferTooShort)?;
+                Ok(($name::read_be(bytes)?, BYTE_ARRAY_LEN))
+            }
+
+            /// Creates a number from its representation in little-endian order, that occupies the first
+            /// `BYTE_LEN` bytes of `buf`. Returns the number and the number of bytes read.
+            pub fn read_le_bytes(buf: &[u8]) -> Result<(Self, usize), FromBytesError> {
+                let bytes = buf.get(..BYTE_ARRAY_LEN).ok_or(FromBytesError::BufferTooShort)?;
+                Ok(($name::read_le(bytes)?, BYTE_ARRAY_LEN))
+            }
+
+            /// Creates a number from its representation in native order, that occupies the first
+            /// `BYTE_LEN` bytes of `buf`. Returns the number and the number of bytes read.
+            ///
+            /// As the target platform’s native endianness is used, portable code should use `read_be_bytes()`
+            /// or `read_le_bytes()`, as appropriate, instead.
+            #[inline(always)]
+            pub fn read_ne_bytes(buf: &[u8]) -> Result<(Self, usize), FromBytesError> {
+                #[cfg(target_endian = "little")]
+                {
+                    $name::read_le_bytes(buf)
+                }
+                #[cfg(target_endian = "big")]
+                {
+                    $name::read_be_bytes(buf)
+                }
+            }
+
+            // Creates a number from its big-endian representation. `bytes` must be `BYTE_ARRAY_LEN` long.
+            fn read_be(bytes: &[u8]) -> Result<Self, FromBytesError> {
                 let flags_byte = bytes[0];
                 match $name::process_flags_byte(flags_byte)? {
                     FlagsByteValue::Special(sv) => Ok(sv),
@@ -371,8 +566,8 @@ macro_rules! fdec {
                 }
             }
 
-            /// Creates a number from its representation as a byte array in little-endian order.
-            pub fn from_le_bytes(bytes: &[u8; BYTE_ARRAY_LEN]) -> Result<Self, FromBytesError> {
+            // Creates a number from its little-endian representation. `bytes` must be `BYTE_ARRAY_LEN` long.
+            fn read_le(bytes: &[u8]) -> Result<Self, FromBytesError> {
                 let flags_byte = bytes[BYTE_ARRAY_LEN - 1];
                 match $name::process_flags_byte(flags_byte)? {
                     FlagsByteValue::Special(sv) => Ok(sv),
@@ -391,22 +586,6 @@ macro_rules! fdec {
                 }
             }
 
-            /// Creates a number from its representation as a byte array in native order.
-            ///
-            /// As the target platform’s native endianness is used, portable code should use `from_be_bytes()`
-            /// or `from_le_bytes()`, as appropriate, instead.
-            #[inline(always)]
-            pub fn from_ne_bytes(bytes: &[u8; BYTE_ARRAY_LEN]) -> Result<Self, FromBytesError> {
-                #[cfg(target_endian = "little")]
-                {
-                    $name::from_le_bytes(bytes)
-                }
-                #[cfg(target_endian = "big")]
-                {
-                    $name::from_be_bytes(bytes)
-                }
-            }
-
             /// Returns infinity of the same sign as this number.
             #[inline(always)]
             fn to_signed_infinity(&self) -> Self {
@@ -416,30 +595,14 @@ macro_rules! fdec {
             /// Returns the memory representation of this number as a byte array in big-endian (network) byte order.
             pub fn to_be_bytes(self) -> [u8; BYTE_ARRAY_LEN] {
                 let mut bytes: [u8; BYTE_ARRAY_LEN] = [0; BYTE_ARRAY_LEN];
-                bytes[0] = self.flags_byte();
-                let mut bytes_idx = BYTE_ARRAY_LEN;
-                for unit in self.magnitude {
-                    let unit_bytes = unit.to_be_bytes();
-                    bytes_idx -= UNIT_BYTES;
-                    for j in 0..UNIT_BYTES {
-                        bytes[bytes_idx + j] = unit_bytes[j];
-                    }
-                }
+                self.write_be(&mut bytes);
                 bytes
             }
 
             /// Returns the memory representation of this number as a byte array in little-endian byte order.
             pub fn to_le_bytes(self) -> [u8; BYTE_ARRAY_LEN] {
                 let mut bytes: [u8; BYTE_ARRAY_LEN] = [0; BYTE_ARRAY_LEN];
-                bytes[BYTE_ARRAY_LEN - 1] = self.flags_byte();
-                let mut bytes_idx = 0;
-                for unit in self.magnitude {
-                    let unit_bytes = unit.to_le_bytes();
-                    for j in 0..UNIT_BYTES {
-                        bytes[bytes_idx + j] = unit_bytes[j];
-                    }
-                    bytes_idx += UNIT_BYTES;
-                }
+                self.write_le(&mut bytes);
                 bytes
             }
 
@@ -459,6 +622,272 @@ macro_rules! fdec {
                 }
             }
 
+            /// Writes the memory representation of this number in big-endian (network) byte order to the first
+            /// `BYTE_LEN` bytes of `buf`. Returns the number of bytes written.
+            pub fn write_be_bytes(&self, buf: &mut [u8]) -> Result<usize, FromBytesError> {
+                let bytes = buf.get_mut(..BYTE_ARRAY_LEN).ok_or(FromBytesError::BufferTooShort)?;
+                self.write_be(bytes);
+                Ok(BYTE_ARRAY_LEN)
+            }
+
+            /// Writes the memory representation of this number in little-endian byte order to the first
+            /// `BYTE_LEN` bytes of `buf`. Returns the number of bytes written.
+            pub fn write_le_bytes(&self, buf: &mut [u8]) -> Result<usize, FromBytesError> {
+                let bytes = buf.get_mut(..BYTE_ARRAY_LEN).ok_or(FromBytesError::BufferTooShort)?;
+                self.write_le(bytes);
+                Ok(BYTE_ARRAY_LEN)
+            }
+
+            /// Writes the memory representation of this number in native byte order to the first
+            /// `BYTE_LEN` bytes of `buf`. Returns the number of bytes written.
+            ///
+            /// As the target platform’s native endianness is used, portable code should use `write_be_bytes()`
+            /// or `write_le_bytes()`, as appropriate, instead.
+            #[inline(always)]
+            pub fn write_ne_bytes(&self, buf: &mut [u8]) -> Result<usize, FromBytesError> {
+                #[cfg(target_endian = "little")]
+                {
+                    self.write_le_bytes(buf)
+                }
+                #[cfg(target_endian = "big")]
+                {
+                    self.write_be_bytes(buf)
+                }
+            }
+
+            // Writes the big-endian representation of the number. `bytes` must be `BYTE_ARRAY_LEN` long.
+            fn write_be(&self, bytes: &mut [u8]) {
+                bytes[0] = self.flags_byte();
+                let mut bytes_idx = BYTE_ARRAY_LEN;
+                for unit in self.magnitude {
+                    bytes_idx -= UNIT_BYTES;
+                    bytes[bytes_idx..bytes_idx + UNIT_BYTES].copy_from_slice(&unit.to_be_bytes());
+                }
+            }
+
+            // Writes the little-endian representation of the number. `bytes` must be `BYTE_ARRAY_LEN` long.
+            fn write_le(&self, bytes: &mut [u8]) {
+                bytes[BYTE_ARRAY_LEN - 1] = self.flags_byte();
+                let mut bytes_idx = 0;
+                for unit in self.magnitude {
+                    bytes[bytes_idx..bytes_idx + UNIT_BYTES].copy_from_slice(&unit.to_le_bytes());
+                    bytes_idx += UNIT_BYTES;
+                }
+            }
+
+            /// Returns a representation of this number as a byte array, which sorts lexicographically in
+            /// the same order as the numbers are ordered by `total_cmp()`.
+            ///
+            /// The first byte denotes the class of the value (-Infinity, negative, positive or zero,
+            /// Infinity and NaN), the rest hold the magnitude in big-endian order. Magnitudes of negative
+            /// numbers are inverted, so that larger magnitudes come first. This makes the representation
+            /// suitable for keys in ordered key-value stores.
+            pub fn to_sortable_bytes(self) -> [u8; BYTE_ARRAY_LEN] {
+                let mut bytes: [u8; BYTE_ARRAY_LEN] = [0; BYTE_ARRAY_LEN];
+                bytes[0] = if self.is_nan() {
+                    SORTABLE_NAN
+                } else if self.is_infinite() {
+                    if self.is_sign_negative() { SORTABLE_NEG_INFINITY } else { SORTABLE_INFINITY }
+                } else if self.is_sign_negative() {
+                    SORTABLE_NEGATIVE
+                } else {
+                    SORTABLE_POSITIVE
+                };
+                if self.is_special() {
+                    return bytes;
+                }
+                let neg = self.is_sign_negative();
+                for (i, unit) in self.magnitude.iter().rev().enumerate() {
+                    let unit = if neg { !unit } else { *unit };
+                    let start = 1 + i * UNIT_BYTES;
+                    bytes[start..start + UNIT_BYTES].copy_from_slice(&unit.to_be_bytes());
+                }
+                bytes
+            }
+
+            /// Creates a number from its representation produced by `to_sortable_bytes()`.
+            ///
+            /// Fails with `FromBytesError::InvalidFormat` if the bytes can't be produced by `to_sortable_bytes()`,
+            /// i.e. a special value has non-zero bytes after the leading one, or a negative value has zero magnitude.
+            pub fn from_sortable_bytes(bytes: &[u8; BYTE_ARRAY_LEN]) -> Result<Self, FromBytesError> {
+                let special = match bytes[0] {
+                    SORTABLE_NEG_INFINITY => Some($name::NEG_INFINITY),
+                    SORTABLE_INFINITY => Some($name::INFINITY),
+                    SORTABLE_NAN => Some($name::NAN),
+                    SORTABLE_NEGATIVE | SORTABLE_POSITIVE => None,
+                    _ => return Err(FromBytesError::InvalidFlags),
+                };
+                if let Some(value) = special {
+                    if bytes[1..].iter().any(|b| *b != 0) {
+                        return Err(FromBytesError::InvalidFormat);
+                    }
+                    return Ok(value);
+                }
+                let neg = bytes[0] == SORTABLE_NEGATIVE;
+                let mut magnitude: [Unit; M_LENGTH] = [0; M_LENGTH];
+                for (i, chunk) in bytes[1..].chunks(UNIT_BYTES).enumerate() {
+                    let mut unit_bytes: [u8; UNIT_BYTES] = [0; UNIT_BYTES];
+                    unit_bytes.copy_from_slice(chunk);
+                    let unit = Unit::from_be_bytes(unit_bytes);
+                    magnitude[M_LENGTH - 1 - i] = if neg { !unit } else { unit };
+                }
+                if neg && is_magnitude_zero(&magnitude) {
+                    return Err(FromBytesError::InvalidFormat);
+                }
+                Ok($name::from_le_units(neg, magnitude))
+            }
+
+            /// Returns the unscaled value of this number as a two's-complement integer in big-endian order, together
+            /// with the scale of the type. This is how decimals are represented in Avro, Parquet and Java's
+            /// `BigDecimal.unscaledValue().toByteArray()`.
+            ///
+            /// The array has one byte more than the magnitude of the type, so it can hold any value of the type.
+            /// Fails with `TryFromNumberError::Special` if the number is NaN or ±Infinity.
+            pub fn to_twos_complement_be(&self) -> Result<([u8; BYTE_ARRAY_LEN], isize), TryFromNumberError> {
+                if self.is_special() {
+                    return Err(TryFromNumberError::Special);
+                }
+                let mut bytes: [u8; BYTE_ARRAY_LEN] = [0; BYTE_ARRAY_LEN];
+                for (i, unit) in self.magnitude.iter().rev().enumerate() {
+                    let start = 1 + i * UNIT_BYTES;
+                    bytes[start..start + UNIT_BYTES].copy_from_slice(&unit.to_be_bytes());
+                }
+                if self.is_sign_negative() {
+                    negate_twos_complement(bytes.iter_mut().rev());
+                }
+                Ok((bytes, $name::SCALE as isize))
+            }
+
+            /// Creates a number from an unscaled two's-complement integer in big-endian order, that represents
+            /// a value with the given scale. The integer may have any length, an empty array represents zero.
+            /// The scale may be negative, as in Avro, Parquet and Java's `BigDecimal`, then the value is the
+            /// integer multiplied by `10^-scale`.
+            ///
+            /// The value is rounded with the given mode if `scale` is larger than the scale of the type.
+            /// Fails with `TryFromNumberError::Overflow` if the value doesn't fit into the type.
+            pub fn from_twos_complement_be(bytes: &[u8], scale: isize, mode: RoundingMode)
+                -> Result<Self, TryFromNumberError> {
+                let neg = bytes.first().map_or(false, |b| b & 0x80 != 0);
+                let mut magnitude: Vec<u8> = bytes.iter().rev().cloned().collect();
+                if neg {
+                    negate_twos_complement(magnitude.iter_mut());
+                }
+                $name::from_le_magnitude_scaled(neg, &magnitude, scale, mode)
+            }
+
+            /// Maximum length of the compact representation of numbers.
+            pub const MAX_COMPACT_LEN: usize = 1 + COMPACT_EXTRA_LEN_BYTES + MAGNITUDE_BYTES;
+
+            /// Writes a compact variable-length representation of this number to `out` and returns
+            /// the number of bytes written.
+            ///
+            /// The representation consists of a header, that holds the flags and the length of the magnitude,
+            /// followed by the magnitude in big-endian order without leading zero bytes. Small values take
+            /// just a few bytes, special values and zero take a single byte.
+            ///
+            /// Panics if `out` is too short to hold the representation. A buffer of `MAX_COMPACT_LEN`
+            /// bytes is always enough.
+            pub fn encode_compact(&self, out: &mut [u8]) -> usize {
+                let bytes = self.to_be_bytes();
+                let magnitude = &bytes[1..];
+                let magnitude = &magnitude[magnitude.iter().take_while(|b| **b == 0).count()..];
+                let len = magnitude.len();
+                let mut n = 1;
+                if len < COMPACT_EXTENDED_LEN {
+                    out[0] = bytes[0] | (len << COMPACT_FLAGS_BITS) as u8;
+                } else {
+                    out[0] = bytes[0] | (COMPACT_EXTENDED_LEN << COMPACT_FLAGS_BITS) as u8;
+                    let mut extra = len - COMPACT_EXTENDED_LEN;
+                    while extra >= 0x80 {
+                        out[n] = (extra & 0x7f) as u8 | 0x80;
+                        extra >>= 7;
+                        n += 1;
+                    }
+                    out[n] = extra as u8;
+                    n += 1;
+                }
+                out[n..n + len].copy_from_slice(magnitude);
+                n + len
+            }
+
+            /// Creates a number from its compact representation produced by `encode_compact()`.
+            /// Returns the number and the number of bytes it took. Bytes that follow the representation are ignored.
+            pub fn decode_compact(bytes: &[u8]) -> Result<(Self, usize), FromBytesError> {
+                let (header_len, len) = $name::decode_compact_header(bytes)?;
+                let end = header_len + len;
+                if bytes.len() < end {
+                    return Err(FromBytesError::BufferTooShort);
+                }
+                let mut be_bytes = [0; BYTE_ARRAY_LEN];
+                be_bytes[0] = bytes[0] & COMPACT_FLAGS_MASK;
+                be_bytes[BYTE_ARRAY_LEN - len..].copy_from_slice(&bytes[header_len..end]);
+                Ok(($name::from_be_bytes(&be_bytes)?, end))
+            }
+
+            /// Writes the compact representation of this number to `writer` and returns the number of bytes written.
+            pub fn write_compact<W: io::Write>(&self, writer: &mut W) -> io::Result<usize> {
+                let mut buf = [0; $name::MAX_COMPACT_LEN];
+                let n = self.encode_compact(&mut buf);
+                writer.write_all(&buf[..n])?;
+                Ok(n)
+            }
+
+            /// Reads a number in the compact representation from `reader`. Reads exactly as many bytes
+            /// as the representation takes.
+            pub fn read_compact<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+                let mut buf = [0; $name::MAX_COMPACT_LEN];
+                let mut n = 1;
+                reader.read_exact(&mut buf[..1])?;
+                if (buf[0] >> COMPACT_FLAGS_BITS) as usize == COMPACT_EXTENDED_LEN {
+                    loop {
+                        if n == 1 + COMPACT_EXTRA_LEN_BYTES {
+                            return Err(io::Error::new(io::ErrorKind::InvalidData, "compact length is too long"));
+                        }
+                        reader.read_exact(&mut buf[n..n + 1])?;
+                        n += 1;
+                        if buf[n - 1] & 0x80 == 0 {
+                            break;
+                        }
+                    }
+                }
+                let invalid_data = |e| io::Error::new(io::ErrorKind::InvalidData, format!("invalid compact representation: {:?}", e));
+                let (_, len) = $name::decode_compact_header(&buf[..n]).map_err(invalid_data)?;
+                reader.read_exact(&mut buf[n..n + len])?;
+                $name::decode_compact(&buf[..n + len]).map(|(v, _)| v).map_err(invalid_data)
+            }
+
+            // Returns the lengths of the header and of the magnitude of a compact representation.
+            fn decode_compact_header(bytes: &[u8]) -> Result<(usize, usize), FromBytesError> {
+                let header = *bytes.first().ok_or(FromBytesError::BufferTooShort)?;
+                let mut len = (header >> COMPACT_FLAGS_BITS) as usize;
+                let mut n = 1;
+                if len == COMPACT_EXTENDED_LEN {
+                    let mut shift = 0;
+                    loop {
+                        let b = *bytes.get(n).ok_or(FromBytesError::BufferTooShort)?;
+                        n += 1;
+                        len += ((b & 0x7f) as usize) << shift;
+                        if len > MAGNITUDE_BYTES {
+                            return Err(FromBytesError::Overflow);
+                        }
+                        if b & 0x80 == 0 {
+                            break;
+                        }
+                        shift += 7;
+                        if n > COMPACT_EXTRA_LEN_BYTES {
+                            return Err(FromBytesError::Overflow);
+                        }
+                    }
+                }
+                if len > MAGNITUDE_BYTES {
+                    return Err(FromBytesError::Overflow);
+                }
+                if len > 0 && Flags::from(header) & FLAGS_SPECIAL != 0 {
+                    return Err(FromBytesError::InvalidFlags);
+                }
+                Ok((n, len))
+            }
+
             /// Creates a number with the given magnitude (in little-endian units order).
             /// `neg` defines if a negative (if `true`) or a positive (if `false`) value will be created.
             /// The caller must guarantee that `-0` is not created.
@@ -478,6 +907,49 @@ macro_rules! fdec {
                 false
             }
 
+            /// Creates a number from its magnitude `bytes` in little-endian order, that represent a value with the
+            /// given scale, which may be negative. The value is rounded with the given mode if the scale is larger
+            /// than the scale of the type.
+            fn from_le_magnitude_scaled(neg: bool, bytes: &[u8], scale: isize, mode: RoundingMode)
+                -> Result<Self, TryFromNumberError> {
+                let mut mag: Vec<Unit> = bytes.chunks(UNIT_BYTES).map(|chunk| {
+                    let mut unit_bytes: [u8; UNIT_BYTES] = [0; UNIT_BYTES];
+                    unit_bytes[..chunk.len()].copy_from_slice(chunk);
+                    Unit::from_le_bytes(unit_bytes)
+                }).collect();
+                if mag.len() < M_LENGTH {
+                    mag.resize(M_LENGTH, 0);
+                }
+                if mag.iter().all(|u| *u == 0) {
+                    return Ok($name::ZERO);
+                }
+                // Any unit has at most UNIT_BASE_POWER + 1 decimal digits, so shifts by more digits than that
+                // either discard the whole magnitude or overflow, and don't need to be done digit by digit.
+                let shift = scale.checked_sub($name::SCALE as isize).ok_or(TryFromNumberError::Overflow)?;
+                if shift < 0 && shift.unsigned_abs() > M_LENGTH * (UNIT_BASE_POWER + 1) {
+                    return Err(TryFromNumberError::Overflow);
+                }
+                if shift > 0 {
+                    let rem = if shift as usize > mag.len() * (UNIT_BASE_POWER + 1) {
+                        mag.iter_mut().for_each(|u| *u = 0);
+                        Remainder::BelowHalf
+                    } else {
+                        shift_decimal_right(&mut mag, shift as usize)
+                    };
+                    if mode.increments(neg, mag[0] & 1 == 1, rem) {
+                        add_unit(&mut mag, 1);
+                    }
+                }
+                if mag[M_LENGTH..].iter().any(|u| *u != 0) {
+                    return Err(TryFromNumberError::Overflow);
+                }
+                let mut num = $name::from_le_units(neg, magnitude_from_slice(&mag[..M_LENGTH]));
+                if shift < 0 && num.move_point_right(shift.unsigned_abs()) {
+                    return Err(TryFromNumberError::Overflow);
+                }
+                Ok(num)
+            }
+
             /// Creates a number from its representation as a byte array in little-endian order.
             fn process_flags_byte(byte: u8) -> Result<FlagsByteValue, FromBytesError> {
                 let flags = Flags::from(byte);
@@ -500,11 +972,189 @@ macro_rules! fdec {
                 Ok(FlagsByteValue::Simple(flags & FLAG_NEGATIVE != 0))
             }
 
+            // Checks that units in the `repr(C)` layout of the type (the magnitude followed by the flags)
+            // hold a valid number. Special values must have zero magnitude, and zero must not be negative.
+            #[allow(dead_code)]
+            fn is_valid_repr(units: &[Unit; M_LENGTH + 1]) -> bool {
+                let flags = units[M_LENGTH];
+                let flags_byte = flags.to_le_bytes()[0];
+                if Flags::from(flags_byte) != flags {
+                    return false;
+                }
+                let mut magnitude = [0; M_LENGTH];
+                magnitude.copy_from_slice(&units[..M_LENGTH]);
+                match $name::process_flags_byte(flags_byte) {
+                    Ok(FlagsByteValue::Special(sv)) => sv.flags == flags && is_magnitude_zero(&magnitude),
+                    Ok(FlagsByteValue::Simple(neg)) => !neg || !is_magnitude_zero(&magnitude),
+                    Err(_) => false,
+                }
+            }
+
             // Returns the byte that holds the number flags.
             #[inline(always)]
             fn flags_byte(self) -> u8 {
                 self.flags.to_be_bytes()[UNIT_BYTES - 1]
             }
+
+            /// Represents the absolute value of the number as a binary fraction `q * 2^exp`, where `q`
+            /// has its highest bit set. Returns `q`, `exp` and the flag that tells if there are non-zero
+            /// bits below `q`. The number must be normal and non-zero.
+            fn to_binary_fraction(&self) -> (u64, i32, bool) {
+                // Align the magnitude and the denominator so that 1 <= a / b < 2
+                let mut a = [0; M_LENGTH + 1];
+                let mut b = [0; M_LENGTH + 1];
+                copy(&self.magnitude, &mut a);
+                copy(&ONE.magnitude, &mut b);
+                let (a_bits, b_bits) = (bit_length(&a), bit_length(&b));
+                let mut exp = a_bits as i32 - b_bits as i32;
+                if a_bits > b_bits {
+                    shl_bits(&mut b, a_bits - b_bits);
+                } else {
+                    shl_bits(&mut a, b_bits - a_bits);
+                }
+                if cmp_magnitudes(&a, &b) == Ordering::Less {
+                    shl_bits(&mut a, 1);
+                    exp -= 1;
+                }
+
+                // Binary long division, a < 2b on every step
+                let mut q: u64 = 0;
+                for _ in 0..64 {
+                    q <<= 1;
+                    if cmp_magnitudes(&a, &b) != Ordering::Less {
+                        sub_from_greater(&mut a, &b);
+                        q |= 1;
+                    }
+                    shl_bits(&mut a, 1);
+                }
+                (q, exp - 63, weight(&a) != 0)
+            }
+
+            /// Returns the number that corresponds to a float value of the given category, if the value
+            /// is special or zero.
+            fn from_float_category(category: FpCategory, neg: bool) -> Option<Self> {
+                match category {
+                    FpCategory::Nan => Some($name::NAN),
+                    FpCategory::Infinite => Some(if neg { $name::NEG_INFINITY } else { $name::INFINITY }),
+                    FpCategory::Zero => Some($name::ZERO),
+                    FpCategory::Normal | FpCategory::Subnormal => None,
+                }
+            }
+
+            /// Creates a number from the binary fraction `±m * 2^exp`, rounding it to the type scale
+            /// with the given mode. Values that are too large are converted to ±Infinity.
+            fn from_binary_fraction(neg: bool, m: u64, exp: i32, mode: RoundingMode) -> Self {
+                let overflow = if neg { $name::NEG_INFINITY } else { $name::INFINITY };
+
+                // Extra units hold the bits of `m * 10^SCALE` that don't fit into the magnitude
+                let mut mag = [0; M_LENGTH + 64 / UNIT_BITS];
+                let mut mv = m as u128;
+                for u in mag.iter_mut() {
+                    *u = mv as Unit;
+                    mv >>= UNIT_BITS;
+                }
+                let mut scale = $name::SCALE;
+                while scale >= UNIT_BASE_POWER {
+                    multiply_by_unit(&mut mag, UNIT_BASE);
+                    scale -= UNIT_BASE_POWER;
+                }
+                multiply_by_unit(&mut mag, (10 as Unit).pow(scale as u32));
+
+                let rem = if exp >= 0 {
+                    if bit_length(&mag) + exp as usize > M_LENGTH * UNIT_BITS {
+                        return overflow;
+                    }
+                    shl_bits(&mut mag, exp as usize);
+                    Remainder::Zero
+                } else {
+                    shr_bits(&mut mag, exp.unsigned_abs() as usize)
+                };
+                if mode.increments(neg, mag[0] & 1 == 1, rem) {
+                    add_unit(&mut mag, 1);
+                }
+                if weight(&mag) > M_LENGTH {
+                    return overflow;
+                }
+                $name::from_le_units(neg, magnitude_from_slice(&mag[..M_LENGTH]))
+            }
+
+            /// Creates a number from the decimal fraction `±0.d1d2...dn * 10^exp`, rounding it to the type
+            /// scale with the given mode. Values that are too large are converted to ±Infinity.
+            fn from_decimal_digits(neg: bool, digits: &[u8], exp: i32, mode: RoundingMode) -> Self {
+                let overflow = if neg { $name::NEG_INFINITY } else { $name::INFINITY };
+
+                // Power of 10 of the last digit relative to the ulp
+                let shift = exp as isize - digits.len() as isize + $name::SCALE as isize;
+                let kept = (digits.len() as isize + shift.min(0)).max(0) as usize;
+                let mut mag = [0; M_LENGTH];
+                for d in digits[..kept].iter() {
+                    if multiply_by_unit(&mut mag, 10) != 0 || add_unit(&mut mag, *d as Unit) != 0 {
+                        return overflow;
+                    }
+                }
+                let rem = if digits.len() as isize + shift < 0 {
+                    Remainder::BelowHalf  // The first digit is below the last kept one
+                } else {
+                    match digits[kept..].split_first() {
+                        Some((d, rest)) => Remainder::from_digit(*d, rest.iter().any(|d| *d != 0)),
+                        None => Remainder::Zero,
+                    }
+                };
+                if mode.increments(neg, mag[0] & 1 == 1, rem) && add_unit(&mut mag, 1) != 0 {
+                    return overflow;
+                }
+                let mut num = $name::from_le_units(neg, mag);
+                if shift > 0 && num.move_point_right(shift as usize) {
+                    return overflow;
+                }
+                num
+            }
+
+            /// Converts the number to another fdec type. The magnitude is scaled down here, if necessary,
+            /// and then scaled up and re-united by the target type. Without the rounding mode, fails
+            /// if the scaling down discards non-zero digits.
+            fn convert<N: Number>(&self, mode: Option<RoundingMode>) -> Result<N, TryFromNumberError> {
+                if self.is_special() {
+                    return Ok(if self.is_nan() {
+                        N::nan()
+                    } else if self.is_sign_negative() {
+                        N::neg_infinity()
+                    } else {
+                        N::infinity()
+                    });
+                }
+                let neg = self.is_sign_negative();
+                let mut mag = self.magnitude;
+                let mut scale = $name::SCALE;
+                if scale > N::SCALE {
+                    let rem = shift_decimal_right(&mut mag, scale - N::SCALE);
+                    match mode {
+                        None if rem != Remainder::Zero => return Err(TryFromNumberError::Fraction),
+                        Some(mode) if mode.increments(neg, mag[0] & 1 == 1, rem) => {
+                            add_unit(&mut mag, 1);  // Doesn't overflow, as the magnitude was divided by 10 at least
+                        }
+                        _ => {}
+                    }
+                    scale = N::SCALE;
+                }
+                let mut bytes = [0; M_LENGTH * UNIT_BYTES];
+                for (chunk, u) in bytes.chunks_mut(UNIT_BYTES).zip(mag.iter()) {
+                    chunk.copy_from_slice(&u.to_le_bytes());
+                }
+                N::from_le_magnitude_bytes(neg, &bytes, scale).ok_or(TryFromNumberError::Overflow)
+            }
+
+            /// Splits the number into its sign, integral part and the remainder of its fraction part.
+            /// Fails if the number is special or its integral part doesn't fit into `u128`.
+            fn to_integral_parts(&self) -> Result<(bool, u128, Remainder), TryFromNumberError> {
+                if self.is_special() {
+                    return Err(TryFromNumberError::Special);
+                }
+                let mut mag = self.magnitude;
+                let rem = shift_decimal_right(&mut mag, $name::SCALE);
+                let int = magnitude_to_u128(&mag).ok_or(TryFromNumberError::Overflow)?;
+                Ok((self.is_sign_negative(), int, rem))
+            }
         }
 
         impl Default for $name {
@@ -607,6 +1257,21 @@ macro_rules! fdec {
             }
         }
 
+        /// Hashes the number consistently with `PartialEq`. As NaN is not equal to itself, the type
+        /// doesn't implement `Eq` and can't be a `HashMap` or `HashSet` key, wrap it as `Total<$name>` instead.
+        impl Hash for $name {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                if self.is_special() {
+                    self.flags.hash(state);
+                } else if is_magnitude_zero(&self.magnitude) {
+                    FLAGS_NO.hash(state);       // Zeros are equal regardless of the sign
+                } else {
+                    self.flags.hash(state);
+                    self.magnitude.hash(state);
+                }
+            }
+        }
+
         impl PartialOrd for $name {
             fn partial_cmp(&self, other: &$name) -> Option<Ordering> {
                 if self.is_special() {
@@ -1122,6 +1787,67 @@ macro_rules! fdec {
             }
         }
 
+        impl Sum for $name {
+            /// Adds up the numbers exactly, so intermediate sums may exceed the type range as long as
+            /// the total fits into it.
+            fn sum<I: Iterator<Item = $name>>(iter: I) -> Self {
+                // Positive and negative terms are added up separately. Extra units prevent overflow
+                // for up to 2^64 terms.
+                let mut pos = [0; M_LENGTH + 64 / UNIT_BITS];
+                let mut neg = [0; M_LENGTH + 64 / UNIT_BITS];
+                let (mut nan, mut inf, mut neg_inf) = (false, false, false);
+                for v in iter {
+                    if v.is_special() {
+                        if v.is_nan() {
+                            nan = true;
+                        } else if v.is_sign_negative() {
+                            neg_inf = true;
+                        } else {
+                            inf = true;
+                        }
+                    } else {
+                        let acc = if v.is_sign_negative() { &mut neg } else { &mut pos };
+                        let carry = add_magnitude(&mut acc[..M_LENGTH], &v.magnitude);
+                        add_unit(&mut acc[M_LENGTH..], carry);
+                    }
+                }
+
+                if nan || (inf && neg_inf) {
+                    return $name::NAN;
+                }
+                if inf || neg_inf {
+                    return if inf { $name::INFINITY } else { $name::NEG_INFINITY };
+                }
+                let negative = cmp_magnitudes(&pos, &neg) == Ordering::Less;
+                let (mut total, rhs) = if negative { (neg, pos) } else { (pos, neg) };
+                sub_from_greater(&mut total, &rhs);
+                if weight(&total) > M_LENGTH {
+                    return if negative { $name::NEG_INFINITY } else { $name::INFINITY };
+                }
+                $name::from_le_units(negative, magnitude_from_slice(&total[..M_LENGTH]))
+            }
+        }
+
+        impl<'a> Sum<&'a $name> for $name {
+            #[inline(always)]
+            fn sum<I: Iterator<Item = &'a $name>>(iter: I) -> Self {
+                iter.cloned().sum()
+            }
+        }
+
+        impl Product for $name {
+            fn product<I: Iterator<Item = $name>>(iter: I) -> Self {
+                iter.fold($name::one(), |acc, v| acc * v)
+            }
+        }
+
+        impl<'a> Product<&'a $name> for $name {
+            #[inline(always)]
+            fn product<I: Iterator<Item = &'a $name>>(iter: I) -> Self {
+                iter.cloned().product()
+            }
+        }
+
         impl_op_assign!($name, AddAssign, $name, add_assign, add);
         impl_op_assign!($name, SubAssign, $name, sub_assign, sub);
         impl_op_assign!($name, MulAssign, $name, mul_assign, mul);
@@ -1134,8 +1860,10 @@ macro_rules! fdec {
         // Various auxiliary functions
         //
 
+        /// Compares two magnitudes of the same length.
         #[inline(always)]
-        fn cmp_magnitudes(a: &[Unit; M_LENGTH], b: &[Unit; M_LENGTH]) -> Ordering {
+        fn cmp_magnitudes(a: &[Unit], b: &[Unit]) -> Ordering {
+            debug_assert!(a.len() == b.len());
             for (s, r) in a.iter().rev().zip(b.iter().rev()) {
                 if s > r {
                     return Ordering::Greater;
@@ -1163,9 +1891,10 @@ macro_rules! fdec {
             lo(carry)
         }
 
-        /// Adds `rhs` to `dest` and returns the carry unit if there was overflow.
+        /// Adds `rhs` to `dest` of the same length and returns the carry unit if there was overflow.
         #[inline]
-        fn add_magnitude(dest: &mut [Unit; M_LENGTH], rhs: &[Unit; M_LENGTH]) -> Unit {
+        fn add_magnitude(dest: &mut [Unit], rhs: &[Unit]) -> Unit {
+            debug_assert!(dest.len() == rhs.len());
             let mut carry = 0;
             for (d, r) in dest.iter_mut().zip(rhs.iter()) {
                 let m = (carry as BigUnit) + (*d as BigUnit) + (*r as BigUnit);
@@ -1220,6 +1949,44 @@ macro_rules! fdec {
             lo(carry)
         }
 
+        /// Divides the given magnitude by a single unit `d` in place and returns the remainder.
+        fn div_rem_unit(mag: &mut [Unit], d: Unit) -> Unit {
+            let mut rem = 0;
+            for u in mag.iter_mut().rev() {
+                let (q, r) = div_wide(rem, *u, d);
+                *u = q;
+                rem = r;
+            }
+            rem
+        }
+
+        /// Divides the given magnitude by 10^n in place. Returns the discarded remainder
+        /// compared to the half of the last kept digit.
+        fn shift_decimal_right(mag: &mut [Unit], n: usize) -> Remainder {
+            if n == 0 {
+                return Remainder::Zero;
+            }
+            let mut sticky = false;
+            for _ in 0..(n - 1) / UNIT_BASE_POWER {
+                sticky |= div_rem_unit(mag, UNIT_BASE) != 0;
+            }
+            let rest = (n - 1) % UNIT_BASE_POWER;
+            if rest > 0 {
+                sticky |= div_rem_unit(mag, (10 as Unit).pow(rest as u32)) != 0;
+            }
+            Remainder::from_digit(div_rem_unit(mag, 10) as u8, sticky)
+        }
+
+        /// Negates a two's-complement integer, given as an iterator over its bytes from the least significant one.
+        fn negate_twos_complement<'a, I: Iterator<Item = &'a mut u8>>(bytes: I) {
+            let mut carry = true;
+            for b in bytes {
+                let (v, c) = (!*b).overflowing_add(carry as u8);
+                *b = v;
+                carry = c;
+            }
+        }
+
         /// Calculates `a` * `b`.
         /// Caller is responsible for providing `dest` of a descent size and zeroed initial value.
         #[inline(always)]
@@ -1367,6 +2134,61 @@ macro_rules! fdec {
             };
         }
 
+        /// Returns the number of the significant bits in the slice.
+        #[inline]
+        fn bit_length(mag: &[Unit]) -> usize {
+            let w = weight(mag);
+            if w == 0 {
+                0
+            } else {
+                w * UNIT_BITS - mag[w - 1].leading_zeros() as usize
+            }
+        }
+
+        /// Shifts the given magnitude left by `n` bits in place. The bits that don't fit are lost.
+        fn shl_bits(mag: &mut [Unit], n: usize) {
+            let units = n / UNIT_BITS;
+            let bits = n % UNIT_BITS;
+            for i in (0..mag.len()).rev() {
+                let mut u = if i >= units { mag[i - units] << bits } else { 0 };
+                if bits > 0 && i > units {
+                    u |= mag[i - units - 1] >> (UNIT_BITS - bits);
+                }
+                mag[i] = u;
+            }
+        }
+
+        /// Shifts the given magnitude right by `n` bits in place. Returns the discarded remainder
+        /// compared to the half of the last kept bit.
+        fn shr_bits(mag: &mut [Unit], n: usize) -> Remainder {
+            if n == 0 {
+                return Remainder::Zero;
+            }
+            let rem = if n > bit_length(mag) {
+                if weight(mag) == 0 { Remainder::Zero } else { Remainder::BelowHalf }
+            } else {
+                let (hu, hb) = ((n - 1) / UNIT_BITS, (n - 1) % UNIT_BITS);     // Position of the highest discarded bit
+                let sticky = weight(&mag[..hu]) != 0 || mag[hu] & (((1 as Unit) << hb) - 1) != 0;
+                match (mag[hu] >> hb & 1 == 1, sticky) {
+                    (false, false) => Remainder::Zero,
+                    (false, true) => Remainder::BelowHalf,
+                    (true, false) => Remainder::Half,
+                    (true, true) => Remainder::AboveHalf,
+                }
+            };
+            let units = n / UNIT_BITS;
+            let bits = n % UNIT_BITS;
+            let len = mag.len();
+            for i in 0..len {
+                let mut u = if i + units < len { mag[i + units] >> bits } else { 0 };
+                if bits > 0 && i + units + 1 < len {
+                    u |= mag[i + units + 1] << (UNIT_BITS - bits);
+                }
+                mag[i] = u;
+            }
+            rem
+        }
+
         /// Returns the number of the significant units in the slice.
         #[inline(always)]
         fn weight(mag: &[Unit]) -> usize {
@@ -1378,6 +2200,18 @@ macro_rules! fdec {
             0
         }
 
+        /// Returns the value of the magnitude as `u128`, or `None` if it doesn't fit.
+        fn magnitude_to_u128(mag: &[Unit]) -> Option<u128> {
+            let mut v: u128 = 0;
+            for u in mag[..weight(mag)].iter().rev() {
+                if v >> (128 - UNIT_BITS) != 0 {
+                    return None;
+                }
+                v = (v << UNIT_BITS) | *u as u128;
+            }
+            Some(v)
+        }
+
         #[inline(always)]
         fn magnitude_from_slice(src: &[Unit]) -> [Unit; M_LENGTH] {
             let mut mag = [0; M_LENGTH];
@@ -1493,9 +2327,33 @@ macro_rules! fdec {
         // Interoperability with primitive types
         //
 
-        impl_float_primitive_interop!($name, f32, f32);
-        impl_float_primitive_interop!($name, f64, f64);
+        impl_float_primitive_interop!($name, f32, f32, to_f32, compose_f32, from_f32_exact, decompose_f32, from_f32_shortest, shortest_f32);
+        impl_float_primitive_interop!($name, f64, f64, to_f64, compose_f64, from_f64_exact, decompose_f64, from_f64_shortest, shortest_f64);
         impl_unit_primitive_interop!($name, u8, i8, i8);
+        impl_to_primitive!($name, u8, to_u8_truncated, to_u8_rounded);
+        impl_to_primitive!($name, u16, to_u16_truncated, to_u16_rounded);
+        impl_to_primitive!($name, u32, to_u32_truncated, to_u32_rounded);
+        impl_to_primitive!($name, u64, to_u64_truncated, to_u64_rounded);
+        impl_to_primitive!($name, u128, to_u128_truncated, to_u128_rounded);
+        impl_to_primitive!($name, usize, to_usize_truncated, to_usize_rounded);
+        impl_to_primitive!($name, i8, to_i8_truncated, to_i8_rounded);
+        impl_to_primitive!($name, i16, to_i16_truncated, to_i16_rounded);
+        impl_to_primitive!($name, i32, to_i32_truncated, to_i32_rounded);
+        impl_to_primitive!($name, i64, to_i64_truncated, to_i64_rounded);
+        impl_to_primitive!($name, i128, to_i128_truncated, to_i128_rounded);
+        impl_to_primitive!($name, isize, to_isize_truncated, to_isize_rounded);
+        impl_num_traits!($name);
+        impl_serde!($name);
+        impl_ieee_decimal!($name);
+        impl_postgres!($name);
+        impl_arrow!($name);
+        impl_google!($name);
+        impl_packed_bcd!($name);
+        impl_bytemuck!($name);
+        impl_zerocopy!($name);
+        impl_rkyv!($name);
+        impl_borsh!($name);
+        impl_bincode!($name);
 
         /// Macro for creating number values from other types
         #[macro_export]
@@ -1505,3 +2363,161 @@ macro_rules! fdec {
         }
     }
 }
+
+/// Generates the conversions between an fdec type and its wide companion type, which has twice
+/// the length and twice the scale.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_widening {
+    ($name:ident, $wide:ty) => {
+        impl $name {
+            /// Multiplies two numbers and returns the exact product as the wide companion type.
+            pub fn widening_mul(self, rhs: $name) -> $wide {
+                if self.is_special() || rhs.is_special() {
+                    return <$wide>::from(self * rhs);
+                }
+                let mut magnitude = [0; BIG_M_LENGTH];
+                multiply(&self.magnitude, &rhs.magnitude, &mut magnitude);
+                <$wide>::from_le_units(self.is_sign_negative() ^ rhs.is_sign_negative(), magnitude)
+            }
+
+            /// Converts a number of the wide companion type to this type, rounding it with the given mode.
+            /// Fails if the value doesn't fit into this type.
+            #[inline(always)]
+            pub fn from_wide(v: $wide, mode: RoundingMode) -> Result<Self, TryFromNumberError> {
+                v.to_number(mode)
+            }
+        }
+        impl From<$name> for $wide {
+            /// Converts the number to the wide companion type, which can hold any value of this type.
+            #[inline(always)]
+            fn from(v: $name) -> Self {
+                match v.to_number_exact() {
+                    Ok(w) => w,
+                    Err(_) => unreachable!(),
+                }
+            }
+        }
+        impl ::std::convert::TryFrom<$wide> for $name {
+            type Error = TryFromNumberError;
+
+            /// Converts a number of the wide companion type to this type. Fails if the number has
+            /// more fraction digits than this type can hold or doesn't fit into it.
+            #[inline(always)]
+            fn try_from(v: $wide) -> Result<Self, Self::Error> {
+                v.to_number_exact()
+            }
+        }
+    };
+}
+
+/// Generates the packed storage type for an fdec type. The packed type keeps the flags in the upper bits
+/// of the most significant unit, which is never zero, so the unit is stored as `$nonzero`.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_packed {
+    ($name:ident, $pname:ident, $nonzero:ty) => {
+        // Tags of the packed type, kept in the upper bits of the most significant unit.
+        // Zero is not a valid tag, which gives `Option<$pname>` a niche.
+        const PACKED_TAG_BITS: usize = 3;
+        const PACKED_TAG_SHIFT: usize = UNIT_BITS - PACKED_TAG_BITS;
+        const PACKED_TAG_POSITIVE: Unit = 1;
+        const PACKED_TAG_NEGATIVE: Unit = 2;
+        const PACKED_TAG_INFINITY: Unit = 3;
+        const PACKED_TAG_NEG_INFINITY: Unit = 4;
+        const PACKED_TAG_NAN: Unit = 5;
+
+        /// Packed storage form of numbers. It keeps the flags in the upper three bits of the magnitude,
+        /// so it takes as much memory as the magnitude alone, and `Option` of it takes the same memory.
+        ///
+        /// Numbers whose magnitudes use the upper three bits can't be kept in this form. Numbers are
+        /// converted to it with `TryFrom` and restored with `get()`. Values are compared by their
+        /// representation, so NaN is equal to itself here.
+        #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+        pub struct $pname {
+            low: [Unit; M_LENGTH - 1],
+            high: $nonzero,
+        }
+
+        impl $pname {
+            /// Returns the number kept in this packed form.
+            pub fn get(self) -> $name {
+                let high = self.high.get();
+                match high >> PACKED_TAG_SHIFT {
+                    PACKED_TAG_INFINITY => $name::INFINITY,
+                    PACKED_TAG_NEG_INFINITY => $name::NEG_INFINITY,
+                    PACKED_TAG_NAN => $name::NAN,
+                    tag => {
+                        let mut magnitude = [0; M_LENGTH];
+                        magnitude[..M_LENGTH - 1].copy_from_slice(&self.low);
+                        magnitude[M_LENGTH - 1] = high & (Unit::MAX >> PACKED_TAG_BITS);
+                        $name::from_le_units_unchecked(tag == PACKED_TAG_NEGATIVE, magnitude)
+                    }
+                }
+            }
+        }
+
+        impl ::std::convert::TryFrom<$name> for $pname {
+            type Error = TryFromNumberError;
+
+            /// Converts the number to the packed form. Fails if its magnitude uses the upper three bits.
+            fn try_from(v: $name) -> Result<Self, Self::Error> {
+                let tag = if v.is_nan() {
+                    PACKED_TAG_NAN
+                } else if v.is_infinite() {
+                    if v.is_sign_negative() {
+                        PACKED_TAG_NEG_INFINITY
+                    } else {
+                        PACKED_TAG_INFINITY
+                    }
+                } else if v.is_sign_negative() {
+                    PACKED_TAG_NEGATIVE
+                } else {
+                    PACKED_TAG_POSITIVE
+                };
+                let high = v.magnitude[M_LENGTH - 1];
+                if high >> PACKED_TAG_SHIFT != 0 {
+                    return Err(TryFromNumberError::Overflow);
+                }
+                let mut low = [0; M_LENGTH - 1];
+                low.copy_from_slice(&v.magnitude[..M_LENGTH - 1]);
+                match <$nonzero>::new(high | tag << PACKED_TAG_SHIFT) {
+                    Some(high) => Ok($pname { low, high }),
+                    None => unreachable!(),
+                }
+            }
+        }
+
+        impl From<$pname> for $name {
+            #[inline]
+            fn from(v: $pname) -> Self {
+                v.get()
+            }
+        }
+
+        impl Default for $pname {
+            /// Returns zero.
+            fn default() -> Self {
+                match <$nonzero>::new(PACKED_TAG_POSITIVE << PACKED_TAG_SHIFT) {
+                    Some(high) => $pname {
+                        low: [0; M_LENGTH - 1],
+                        high,
+                    },
+                    None => unreachable!(),
+                }
+            }
+        }
+
+        impl Debug for $pname {
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                Debug::fmt(&self.get(), f)
+            }
+        }
+
+        impl Display for $pname {
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                Display::fmt(&self.get(), f)
+            }
+        }
+    };
+}
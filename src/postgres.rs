@@ -0,0 +1,281 @@
+//! Encoding and decoding of the PostgreSQL binary NUMERIC representation.
+//!
+//! The representation consists of four 16-bit big-endian header words (number of digits, weight,
+//! sign and display scale), followed by base-10000 digits, most significant first. The value is
+//! `sum(digit[i] * 10000^(weight - i))`.
+
+use FromBytesError;
+
+const NBASE_DIGITS: i32 = 4;
+const HEADER_LEN: usize = 8;
+
+const SIGN_POS: u16 = 0x0000;
+const SIGN_NEG: u16 = 0x4000;
+const SIGN_NAN: u16 = 0xc000;
+const SIGN_PINF: u16 = 0xd000;
+const SIGN_NINF: u16 = 0xf000;
+
+/// A NUMERIC value.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Value {
+    /// Finite value `±0.d1d2...dn * 10^exp`, decimal digits are listed most significant first.
+    Finite(bool, Vec<u8>, i32),
+    /// Infinity of the given sign.
+    Infinite(bool),
+    /// NaN.
+    NaN,
+}
+
+/// Encodes the value with the given display scale, which is ignored for special values.
+pub fn encode(v: &Value, dscale: u16) -> Vec<u8> {
+    let (neg, digits, exp) = match *v {
+        Value::Finite(neg, ref digits, exp) => (neg, digits, exp),
+        Value::Infinite(neg) => return header(0, 0, if neg { SIGN_NINF } else { SIGN_PINF }, 0),
+        Value::NaN => return header(0, 0, SIGN_NAN, 0),
+    };
+
+    // Align the digits to the base-10000 digit boundaries
+    let mut weight = (exp - 1).div_euclid(NBASE_DIGITS);
+    let pad = (NBASE_DIGITS - 1 - (exp - 1).rem_euclid(NBASE_DIGITS)) as usize;
+    let mut nbase_digits: Vec<u16> = Vec::with_capacity((pad + digits.len()) / 4 + 1);
+    let mut d = 0;
+    for (i, digit) in digits.iter().enumerate() {
+        d = d * 10 + *digit as u16;
+        if (pad + i) % 4 == 3 {
+            nbase_digits.push(d);
+            d = 0;
+        }
+    }
+    let rest = (pad + digits.len()) % 4;
+    if rest != 0 {
+        nbase_digits.push(d * 10u16.pow(4 - rest as u32));
+    }
+
+    // Strip leading and trailing zeros
+    while let Some(&0) = nbase_digits.last() {
+        nbase_digits.pop();
+    }
+    let lz = nbase_digits.iter().take_while(|d| **d == 0).count();
+    if lz == nbase_digits.len() {
+        return header(0, 0, SIGN_POS, dscale);
+    }
+    weight -= lz as i32;
+
+    let sign = if neg { SIGN_NEG } else { SIGN_POS };
+    let ndigits = nbase_digits.len() - lz;
+    let mut res = header(ndigits as u16, weight as i16, sign, dscale);
+    for d in &nbase_digits[lz..] {
+        res.extend_from_slice(&d.to_be_bytes());
+    }
+    res
+}
+
+/// Decodes a value. The display scale is ignored, because it doesn't affect the value.
+pub fn decode(bytes: &[u8]) -> Result<Value, FromBytesError> {
+    if bytes.len() < HEADER_LEN {
+        return Err(FromBytesError::BufferTooShort);
+    }
+    let word = |i: usize| u16::from_be_bytes([bytes[2 * i], bytes[2 * i + 1]]);
+    let ndigits = word(0) as i16;
+    let weight = word(1) as i16;
+    let neg = match word(2) {
+        SIGN_POS => false,
+        SIGN_NEG => true,
+        SIGN_NAN => return Ok(Value::NaN),
+        SIGN_PINF => return Ok(Value::Infinite(false)),
+        SIGN_NINF => return Ok(Value::Infinite(true)),
+        _ => return Err(FromBytesError::InvalidFormat),
+    };
+    if ndigits < 0 || word(3) > 0x3fff {
+        return Err(FromBytesError::InvalidFormat);
+    }
+    let len = HEADER_LEN + 2 * ndigits as usize;
+    if bytes.len() < len {
+        return Err(FromBytesError::BufferTooShort);
+    }
+    if bytes.len() > len {
+        return Err(FromBytesError::InvalidFormat);
+    }
+    let mut digits = Vec::with_capacity(4 * ndigits as usize);
+    for c in bytes[HEADER_LEN..].chunks(2) {
+        let d = u16::from_be_bytes([c[0], c[1]]);
+        if d > 9999 {
+            return Err(FromBytesError::InvalidFormat);
+        }
+        digits.extend_from_slice(&[
+            (d / 1000) as u8,
+            (d / 100 % 10) as u8,
+            (d / 10 % 10) as u8,
+            (d % 10) as u8,
+        ]);
+    }
+    Ok(Value::Finite(
+        neg,
+        digits,
+        (weight as i32 + 1) * NBASE_DIGITS,
+    ))
+}
+
+fn header(ndigits: u16, weight: i16, sign: u16, dscale: u16) -> Vec<u8> {
+    let mut res = Vec::with_capacity(HEADER_LEN + 2 * ndigits as usize);
+    res.extend_from_slice(&ndigits.to_be_bytes());
+    res.extend_from_slice(&weight.to_be_bytes());
+    res.extend_from_slice(&sign.to_be_bytes());
+    res.extend_from_slice(&dscale.to_be_bytes());
+    res
+}
+
+/// Generates conversions between an fdec type and the PostgreSQL binary NUMERIC representation.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_postgres {
+    ($name:ident) => {
+        impl $name {
+            /// Converts the number to the binary representation of PostgreSQL `NUMERIC`, which is used by
+            /// the binary protocol and `COPY ... WITH (FORMAT binary)`.
+            ///
+            /// The display scale of the value is the scale of the type. NaN and infinities are converted to the
+            /// corresponding special values.
+            pub fn to_pg_numeric(&self) -> Vec<u8> {
+                let v = if self.is_nan() {
+                    $crate::postgres::Value::NaN
+                } else if self.is_infinite() {
+                    $crate::postgres::Value::Infinite(self.is_sign_negative())
+                } else {
+                    let mut digits = to_digits(self.magnitude.to_vec());
+                    digits.reverse();
+                    let exp = digits.len() as i32 - $name::SCALE as i32;
+                    $crate::postgres::Value::Finite(self.is_sign_negative(), digits, exp)
+                };
+                $crate::postgres::encode(&v, $name::SCALE as u16)
+            }
+
+            /// Creates a number from the binary representation of PostgreSQL `NUMERIC`. Values with more
+            /// decimal places than the type are rounded with the given mode.
+            ///
+            /// Fails with `FromBytesError::Overflow` if the value doesn't fit into the type, and with
+            /// `FromBytesError::InvalidFormat` if the bytes are not a valid `NUMERIC` representation.
+            pub fn from_pg_numeric(
+                bytes: &[u8],
+                mode: RoundingMode,
+            ) -> Result<Self, FromBytesError> {
+                match $crate::postgres::decode(bytes)? {
+                    $crate::postgres::Value::NaN => Ok($name::NAN),
+                    $crate::postgres::Value::Infinite(neg) => Ok(if neg {
+                        $name::NEG_INFINITY
+                    } else {
+                        $name::INFINITY
+                    }),
+                    $crate::postgres::Value::Finite(neg, digits, exp) => {
+                        if digits.iter().all(|d| *d == 0) {
+                            return Ok($name::ZERO);
+                        }
+                        let num = $name::from_decimal_digits(neg, &digits, exp, mode);
+                        if num.is_infinite() {
+                            return Err(FromBytesError::Overflow);
+                        }
+                        Ok(num)
+                    }
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn finite(neg: bool, digits: &str, exp: i32) -> Value {
+        Value::Finite(neg, digits.bytes().map(|b| b - b'0').collect(), exp)
+    }
+
+    #[test]
+    fn test_encode() {
+        // 12345.678 with dscale 3
+        assert_eq!(
+            encode(&finite(false, "12345678", 5), 3),
+            [0, 3, 0, 1, 0, 0, 0, 3, 0x00, 0x01, 0x09, 0x29, 0x1a, 0x7c]
+        );
+        // -0.0012 with dscale 4
+        assert_eq!(
+            encode(&finite(true, "12", -2), 4),
+            [0, 1, 0xff, 0xff, 0x40, 0, 0, 4, 0, 12]
+        );
+        // 10000 * 10^8 with trailing zero digits stripped
+        assert_eq!(
+            encode(&finite(false, "1000000000000", 13), 0),
+            [0, 1, 0, 3, 0, 0, 0, 0, 0, 1]
+        );
+        // Zero with leading and trailing zeros
+        assert_eq!(encode(&finite(true, "000", 1), 2), [0, 0, 0, 0, 0, 0, 0, 2]);
+        assert_eq!(encode(&finite(false, "", 0), 0), [0, 0, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_encode_special() {
+        assert_eq!(encode(&Value::NaN, 2), [0, 0, 0, 0, 0xc0, 0, 0, 0]);
+        assert_eq!(
+            encode(&Value::Infinite(false), 2),
+            [0, 0, 0, 0, 0xd0, 0, 0, 0]
+        );
+        assert_eq!(
+            encode(&Value::Infinite(true), 2),
+            [0, 0, 0, 0, 0xf0, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(
+            decode(&[0, 3, 0, 1, 0, 0, 0, 3, 0x00, 0x01, 0x09, 0x29, 0x1a, 0x7c]),
+            Ok(finite(false, "000123456780", 8))
+        );
+        assert_eq!(
+            decode(&[0, 1, 0xff, 0xff, 0x40, 0, 0, 4, 0, 12]),
+            Ok(finite(true, "0012", 0))
+        );
+        assert_eq!(decode(&[0, 0, 0, 0, 0, 0, 0, 2]), Ok(finite(false, "", 4)));
+        assert_eq!(decode(&[0, 0, 0, 0, 0xc0, 0, 0, 0]), Ok(Value::NaN));
+        assert_eq!(
+            decode(&[0, 0, 0, 0, 0xd0, 0, 0, 0]),
+            Ok(Value::Infinite(false))
+        );
+        assert_eq!(
+            decode(&[0, 0, 0, 0, 0xf0, 0, 0, 0]),
+            Ok(Value::Infinite(true))
+        );
+    }
+
+    #[test]
+    fn test_decode_invalid() {
+        assert_eq!(
+            decode(&[0, 0, 0, 0, 0, 0, 0]),
+            Err(FromBytesError::BufferTooShort)
+        );
+        assert_eq!(
+            decode(&[0, 1, 0, 0, 0, 0, 0, 0, 0]),
+            Err(FromBytesError::BufferTooShort)
+        );
+        assert_eq!(
+            decode(&[0, 0, 0, 0, 0, 0, 0, 0, 0]),
+            Err(FromBytesError::InvalidFormat)
+        );
+        assert_eq!(
+            decode(&[0, 0, 0, 0, 0x80, 0, 0, 0]),
+            Err(FromBytesError::InvalidFormat)
+        );
+        assert_eq!(
+            decode(&[0xff, 0xff, 0, 0, 0, 0, 0, 0]),
+            Err(FromBytesError::InvalidFormat)
+        );
+        assert_eq!(
+            decode(&[0, 0, 0, 0, 0, 0, 0x40, 0]),
+            Err(FromBytesError::InvalidFormat)
+        );
+        assert_eq!(
+            decode(&[0, 1, 0, 0, 0, 0, 0, 0, 0x27, 0x10]),
+            Err(FromBytesError::InvalidFormat)
+        );
+    }
+}
@@ -113,37 +113,68 @@ macro_rules! impl_big_primitive_interop {
 #[macro_export]
 #[doc(hidden)]
 macro_rules! impl_float_primitive_interop {
-    ($name:ident, $prim:ty, $itid:ident) => {
+    ($name:ident, $prim:ty, $itid:ident, $to:ident, $compose:ident,
+     $from_exact:ident, $decompose:ident, $from_shortest:ident, $shortest:ident) => {
         impl From<$prim> for $name {
+            /// Converts the exact value of the float, rounding it to the type scale half-to-even.
+            #[inline(always)]
             fn from(v: $prim) -> Self {
-                if !v.is_normal() {
-                    if v.is_nan() {
-                        return $name::NAN;
-                    }
-                    if v.is_infinite() {
-                        return if v.is_sign_negative() {
-                            $name::NEG_INFINITY
-                        } else {
-                            $name::INFINITY
-                        };
-                    }
-                    if v == 0 as $prim {
-                        return $name::ZERO;
-                    }
-                    // Subnormal values are handled as normal
+                $name::$from_exact(v, RoundingMode::HalfEven)
+            }
+        }
+        impl From<$name> for $prim {
+            #[inline(always)]
+            fn from(v: $name) -> Self {
+                v.$to()
+            }
+        }
+        impl $name {
+            #[doc = concat!("Creates a number from the exact binary value of the given `", stringify!($prim), "`.")]
+            ///
+            /// The value is rounded to the type scale with the given rounding mode. NaN and ±Infinity are
+            /// converted to the corresponding special values, values that are too large are converted to ±Infinity.
+            pub fn $from_exact(v: $prim, mode: RoundingMode) -> Self {
+                if let Some(n) = $name::from_float_category(v.classify(), v.is_sign_negative()) {
+                    return n;
                 }
-                let s = &format!("{:.PRECISION$}", v, PRECISION = $name::SCALE);
-                match $name::from_str(s) {
-                    Ok(n) => n,
-                    Err(ParseNumberError::Overflow) => {
-                        if v.is_sign_negative() {
-                            $name::NEG_INFINITY
-                        } else {
-                            $name::INFINITY
-                        }
-                    }
-                    Err(ParseNumberError::InvalidFormat) => unreachable!(),
+                let (m, exp) = $crate::float::$decompose(v);
+                $name::from_binary_fraction(v.is_sign_negative(), m, exp, mode)
+            }
+
+            #[doc = concat!("Creates a number from the shortest decimal representation that identifies the given `", stringify!($prim), "`.")]
+            ///
+            /// This is the value that is displayed when the float is formatted, e.g. `0.1` instead of
+            /// `0.1000000000000000055511151231257827`. It is rounded to the type scale with the given
+            /// rounding mode. NaN and ±Infinity are converted to the corresponding special values,
+            /// values that are too large are converted to ±Infinity.
+            pub fn $from_shortest(v: $prim, mode: RoundingMode) -> Self {
+                if let Some(n) = $name::from_float_category(v.classify(), v.is_sign_negative()) {
+                    return n;
                 }
+                let mut digits = [0; $crate::float::MAX_DIGITS];
+                let (len, exp) = $crate::float::$shortest(v, &mut digits);
+                $name::from_decimal_digits(v.is_sign_negative(), &digits[..len], exp, mode)
+            }
+
+            #[doc = concat!("Converts the number to the closest `", stringify!($prim), "` value.")]
+            ///
+            /// NaN and ±Infinity are converted to the corresponding special values of the float type.
+            /// Numbers that are too large for the float type are converted to ±Infinity.
+            pub fn $to(&self) -> $prim {
+                if self.is_special() {
+                    return if self.is_nan() {
+                        <$prim>::NAN
+                    } else if self.is_sign_negative() {
+                        <$prim>::NEG_INFINITY
+                    } else {
+                        <$prim>::INFINITY
+                    };
+                }
+                if is_magnitude_zero(&self.magnitude) {
+                    return 0.0;
+                }
+                let (q, exp, sticky) = self.to_binary_fraction();
+                $crate::float::$compose(self.is_sign_negative(), q, exp, sticky)
             }
         }
         impl_primitive_arithmetic!($name, $prim);
@@ -187,3 +218,58 @@ macro_rules! impl_primitive_arithmetic {
         impl_op_assign!($name, RemAssign, $prim, rem_assign, rem);
     };
 }
+
+/// Generates implementations of conversions from an fdec type to a primitive integer type.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_to_primitive {
+    ($name:ident, $prim:ty, $truncated:ident, $rounded:ident) => {
+        impl ::std::convert::TryFrom<$name> for $prim {
+            type Error = TryFromNumberError;
+
+            /// Converts the number to the primitive type. Fails if the number has a fraction part.
+            fn try_from(v: $name) -> Result<Self, Self::Error> {
+                match v.to_integral_parts()? {
+                    (neg, int, Remainder::Zero) => primitive_from_integral!($prim, neg, int),
+                    _ => Err(TryFromNumberError::Fraction),
+                }
+            }
+        }
+        impl $name {
+            #[doc = concat!("Converts the number to `", stringify!($prim), "`, discarding its fraction part.")]
+            pub fn $truncated(&self) -> Result<$prim, TryFromNumberError> {
+                let (neg, int, _) = self.to_integral_parts()?;
+                primitive_from_integral!($prim, neg, int)
+            }
+
+            #[doc = concat!("Converts the number to `", stringify!($prim), "`, rounding it to the nearest integer.")]
+            /// Half-way cases are rounded away from zero.
+            pub fn $rounded(&self) -> Result<$prim, TryFromNumberError> {
+                let (neg, int, rem) = self.to_integral_parts()?;
+                let int = match rem {
+                    Remainder::Half | Remainder::AboveHalf => int.checked_add(1).ok_or(TryFromNumberError::Overflow)?,
+                    _ => int,
+                };
+                primitive_from_integral!($prim, neg, int)
+            }
+        }
+    };
+}
+
+/// Converts a sign and an integral magnitude to a primitive integer type.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! primitive_from_integral {
+    ($prim:ty, $neg:expr, $int:expr) => {{
+        let res = if !$neg || $int == 0 {
+            <$prim as ::std::convert::TryFrom<u128>>::try_from($int).ok()
+        } else {
+            // -int == -(int - 1) - 1, which doesn't overflow for the minimum value of signed types
+            <$prim as ::std::convert::TryFrom<u128>>::try_from($int - 1)
+                .ok()
+                .and_then(|v| (0 as $prim).checked_sub(v))
+                .and_then(|v| v.checked_sub(1))
+        };
+        res.ok_or(TryFromNumberError::Overflow)
+    }};
+}
@@ -0,0 +1,60 @@
+// Implementation of `rkyv` traits for the generated types.
+//
+// Numbers are archived as their little-endian byte representation (see `to_le_bytes()`), which
+// has no alignment requirements and is the same on all platforms. Deserializing validates the flags.
+
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use FromBytesError;
+
+/// Error that is produced when an archived number doesn't hold a valid number representation.
+#[derive(Debug)]
+pub struct InvalidNumberError(pub FromBytesError);
+
+impl Display for InvalidNumberError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "invalid archived number: {:?}", self.0)
+    }
+}
+
+impl Error for InvalidNumberError {}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_rkyv {
+    ($name:ident) => {
+        impl $crate::rkyv_crate::Archive for $name {
+            type Archived = [u8; BYTE_ARRAY_LEN];
+            type Resolver = ();
+
+            #[inline]
+            fn resolve(&self, _: Self::Resolver, out: $crate::rkyv_crate::Place<Self::Archived>) {
+                out.write(self.to_le_bytes());
+            }
+        }
+
+        impl<S> $crate::rkyv_crate::Serialize<S> for $name
+        where
+            S: $crate::rkyv_crate::rancor::Fallible + ?Sized,
+        {
+            #[inline]
+            fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+                Ok(())
+            }
+        }
+
+        impl<D> $crate::rkyv_crate::Deserialize<$name, D> for [u8; BYTE_ARRAY_LEN]
+        where
+            D: $crate::rkyv_crate::rancor::Fallible + ?Sized,
+            D::Error: $crate::rkyv_crate::rancor::Source,
+        {
+            fn deserialize(&self, _: &mut D) -> Result<$name, D::Error> {
+                $name::from_le_bytes(self).map_err(|e| {
+                    <D::Error as $crate::rkyv_crate::rancor::Source>::new(
+                        $crate::rkyv::InvalidNumberError(e),
+                    )
+                })
+            }
+        }
+    };
+}
@@ -0,0 +1,220 @@
+//! Serialization of numbers with [serde](https://serde.rs).
+//!
+//! With the `serde` feature enabled, every generated type implements `Serialize` and `Deserialize`
+//! using the lossless [`string`] form. Other representations can be selected for individual fields
+//! with the `#[serde(with = "...")]` attribute:
+//!
+//! - [`number`] writes JSON numbers with arbitrary precision (requires the `serde-arbitrary-precision` feature),
+//! - [`bytes`] writes the flagged byte layout produced by `to_be_bytes()`.
+//!
+//! ```
+//! # #[macro_use] extern crate fdec;
+//! # #[macro_use] extern crate serde_derive;
+//! # extern crate serde;
+//! # extern crate bincode;
+//! fdec64! {
+//!     module dec,
+//!     name Decimal,
+//!     length 2,
+//!     scale 10
+//! }
+//!
+//! use dec::*;
+//!
+//! #[derive(Serialize, Deserialize, PartialEq, Debug)]
+//! struct Trade {
+//!     price: Decimal,
+//!     #[serde(with = "fdec::serde::bytes")]
+//!     amount: Decimal,
+//! }
+//!
+//! # fn main() {
+//! let trade = Trade { price: dec!(15, 1), amount: dec!(-7) };
+//! let encoded = bincode::serialize(&trade).unwrap();
+//! assert_eq!(bincode::deserialize::<Trade>(&encoded).unwrap(), trade);
+//! # }
+//! ```
+
+use serde_crate::de::{self, Deserializer, SeqAccess, Unexpected, Visitor};
+use serde_crate::ser::Serializer;
+use std::fmt;
+use std::marker::PhantomData;
+use {FromBytesError, Number};
+
+/// Gives access to the byte representation of numbers for the [`bytes`] serialization.
+#[doc(hidden)]
+pub trait BeBytes: Number {
+    /// Length of the byte representation.
+    const BYTE_LEN: usize;
+
+    /// Calls `f` with the representation of the number as a byte array in big-endian order.
+    fn with_be_bytes<R, F: FnOnce(&[u8]) -> R>(&self, f: F) -> R;
+
+    /// Creates a number from its representation as a byte array of `BYTE_LEN` bytes in big-endian order.
+    fn from_be_slice(bytes: &[u8]) -> Result<Self, FromBytesError>;
+}
+
+/// Lossless string form, which is used by default.
+///
+/// Numbers are written as decimal strings, special values are written as `NaN`, `Infinity`
+/// and `-Infinity`.
+pub mod string {
+    use super::*;
+
+    /// Serializes a number as a string.
+    pub fn serialize<N: Number, S: Serializer>(v: &N, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(v)
+    }
+
+    /// Deserializes a number from a string.
+    pub fn deserialize<'de, N: Number, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<N, D::Error> {
+        deserializer.deserialize_str(StrVisitor(PhantomData))
+    }
+
+    struct StrVisitor<N>(PhantomData<N>);
+
+    impl<'de, N: Number> Visitor<'de> for StrVisitor<N> {
+        type Value = N;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a decimal number string")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<N, E> {
+            let n = match v {
+                "NaN" => Some(N::nan()),
+                "Infinity" => Some(N::infinity()),
+                "-Infinity" => Some(N::neg_infinity()),
+                _ => v.parse().ok(),
+            };
+            n.ok_or_else(|| E::invalid_value(Unexpected::Str(v), &self))
+        }
+    }
+}
+
+/// JSON numbers with arbitrary precision.
+///
+/// Numbers are written as JSON numbers without losing any digits. Special values cannot be
+/// represented as JSON numbers, so serializing them fails. Numbers in exponent notation
+/// are not accepted.
+///
+/// The module is only available with the `serde-arbitrary-precision` feature, which enables
+/// the `arbitrary_precision` feature of `serde_json`.
+#[cfg(feature = "serde-arbitrary-precision")]
+pub mod number {
+    use super::*;
+    use serde_crate::{ser, Deserialize, Serialize};
+    use serde_json::Number as JsonNumber;
+
+    /// Serializes a number as a JSON number.
+    pub fn serialize<N: Number, S: Serializer>(v: &N, serializer: S) -> Result<S::Ok, S::Error> {
+        if v.is_special() {
+            return Err(ser::Error::custom(format_args!(
+                "{} cannot be represented as a JSON number",
+                v
+            )));
+        }
+        let n: JsonNumber = v.to_string().parse().map_err(ser::Error::custom)?;
+        n.serialize(serializer)
+    }
+
+    /// Deserializes a number from a JSON number.
+    pub fn deserialize<'de, N: Number, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<N, D::Error> {
+        let n = JsonNumber::deserialize(deserializer)?;
+        let s = n.to_string();
+        s.parse().map_err(|_| {
+            de::Error::invalid_value(
+                Unexpected::Other(&s),
+                &"a decimal number that fits the type",
+            )
+        })
+    }
+}
+
+/// Flagged byte layout produced by `to_be_bytes()`.
+///
+/// Numbers are written as byte arrays of fixed length, which is compact in binary formats.
+pub mod bytes {
+    use super::*;
+
+    /// Serializes a number as a byte array.
+    pub fn serialize<N: BeBytes, S: Serializer>(v: &N, serializer: S) -> Result<S::Ok, S::Error> {
+        v.with_be_bytes(|bytes| serializer.serialize_bytes(bytes))
+    }
+
+    /// Deserializes a number from a byte array.
+    pub fn deserialize<'de, N: BeBytes, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<N, D::Error> {
+        deserializer.deserialize_bytes(BytesVisitor(PhantomData))
+    }
+
+    struct BytesVisitor<N>(PhantomData<N>);
+
+    impl<'de, N: BeBytes> Visitor<'de> for BytesVisitor<N> {
+        type Value = N;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "an array of {} bytes", N::BYTE_LEN)
+        }
+
+        fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<N, E> {
+            if v.len() != N::BYTE_LEN {
+                return Err(E::invalid_length(v.len(), &self));
+            }
+            N::from_be_slice(v).map_err(|_| E::invalid_value(Unexpected::Bytes(v), &self))
+        }
+
+        // Formats without native byte arrays (like JSON) represent them as sequences
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<N, A::Error> {
+            let mut bytes = Vec::with_capacity(N::BYTE_LEN);
+            while let Some(b) = seq.next_element::<u8>()? {
+                if bytes.len() == N::BYTE_LEN {
+                    return Err(de::Error::invalid_length(bytes.len() + 1, &self));
+                }
+                bytes.push(b);
+            }
+            self.visit_bytes(&bytes)
+        }
+    }
+}
+
+/// Generates implementations of the serde traits for an fdec type.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_serde {
+    ($name:ident) => {
+        impl $crate::serde_crate::Serialize for $name {
+            fn serialize<S: $crate::serde_crate::Serializer>(
+                &self,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                $crate::serde::string::serialize(self, serializer)
+            }
+        }
+
+        impl<'de> $crate::serde_crate::Deserialize<'de> for $name {
+            fn deserialize<D: $crate::serde_crate::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Self, D::Error> {
+                $crate::serde::string::deserialize(deserializer)
+            }
+        }
+
+        impl $crate::serde::BeBytes for $name {
+            const BYTE_LEN: usize = $name::BYTE_LEN;
+
+            fn with_be_bytes<R, F: FnOnce(&[u8]) -> R>(&self, f: F) -> R {
+                f(&self.to_be_bytes())
+            }
+
+            fn from_be_slice(bytes: &[u8]) -> Result<Self, FromBytesError> {
+                $name::read_be_bytes(bytes).map(|(v, _)| v)
+            }
+        }
+    };
+}
@@ -0,0 +1,67 @@
+// Implementation of `zerocopy` traits for the generated types.
+//
+// The types have a `repr(C)` layout without padding, so `IntoBytes`, `Immutable` and `KnownLayout` are
+// derived for them. Not every bit pattern is a valid number, and the `TryFromBytes` derive can't validate
+// the flags, so the types get their own methods that load numbers from bytes and validate the flags.
+
+// Derives `zerocopy` traits for the numeric type.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! derive_zerocopy {
+    ($item:item) => {
+        use $crate::zerocopy_crate;
+
+        #[derive(
+            zerocopy_crate::IntoBytes, zerocopy_crate::Immutable, zerocopy_crate::KnownLayout,
+        )]
+        #[zerocopy(crate = "self::zerocopy_crate")]
+        $item
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_zerocopy {
+    ($name:ident) => {
+        impl $name {
+            /// Interprets `bytes` as a number in its `repr(C)` layout without copying.
+            ///
+            /// Fails with `FromBytesError::InvalidFormat` if `bytes` has a wrong length or alignment,
+            /// and with `FromBytesError::InvalidFlags` if the bytes don't hold a valid number.
+            pub fn try_ref_from_bytes(bytes: &[u8]) -> Result<&Self, FromBytesError> {
+                let units =
+                    <[Unit; M_LENGTH + 1] as $crate::zerocopy_crate::FromBytes>::ref_from_bytes(
+                        bytes,
+                    )
+                    .map_err(|_| FromBytesError::InvalidFormat)?;
+                if !$name::is_valid_repr(units) {
+                    return Err(FromBytesError::InvalidFlags);
+                }
+                // SAFETY: the type is `repr(C)` with the same size and alignment as the units,
+                // which hold a valid number.
+                Ok(unsafe { &*(units as *const [Unit; M_LENGTH + 1] as *const $name) })
+            }
+
+            /// Reads a number from `bytes` that hold it in its `repr(C)` layout. `bytes` needn't be aligned.
+            ///
+            /// Fails with `FromBytesError::InvalidFormat` if `bytes` has a wrong length,
+            /// and with `FromBytesError::InvalidFlags` if the bytes don't hold a valid number.
+            pub fn try_read_from_bytes(bytes: &[u8]) -> Result<Self, FromBytesError> {
+                let units =
+                    <[Unit; M_LENGTH + 1] as $crate::zerocopy_crate::FromBytes>::read_from_bytes(
+                        bytes,
+                    )
+                    .map_err(|_| FromBytesError::InvalidFormat)?;
+                if !$name::is_valid_repr(&units) {
+                    return Err(FromBytesError::InvalidFlags);
+                }
+                let mut magnitude = [0; M_LENGTH];
+                magnitude.copy_from_slice(&units[..M_LENGTH]);
+                Ok($name {
+                    magnitude,
+                    flags: units[M_LENGTH],
+                })
+            }
+        }
+    };
+}
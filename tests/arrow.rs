@@ -0,0 +1,232 @@
+#![cfg(feature = "arrow")]
+
+extern crate arrow_buffer;
+#[macro_use]
+extern crate fdec;
+
+fdec64! {
+    module price,
+    name Price,
+    length 1,
+    scale 8
+}
+
+fdec8! {
+    module small,
+    name Small,
+    length 2,
+    scale 2
+}
+
+fdec64! {
+    module big,
+    name Big,
+    length 8,
+    scale 20
+}
+
+use arrow_buffer::i256;
+use big::Big;
+use fdec::RoundingMode::*;
+use fdec::{Number, TryFromNumberError};
+use price::Price;
+use small::Small;
+use std::str::FromStr;
+
+fn p(s: &str) -> Price {
+    Price::from_str(s).unwrap()
+}
+
+#[test]
+fn test_to_decimal128() {
+    assert_eq!(p("123.45").to_arrow_decimal128(10, 2, Down), Ok(12345));
+    assert_eq!(p("-123.45").to_arrow_decimal128(10, 2, Down), Ok(-12345));
+    assert_eq!(
+        p("123.45").to_arrow_decimal128(11, 8, Down),
+        Ok(12345000000)
+    );
+    assert_eq!(
+        p("123.45").to_arrow_decimal128(38, 20, Down),
+        Ok(12345 * 10i128.pow(18))
+    );
+    assert_eq!(p("123.45").to_arrow_decimal128(5, -2, HalfUp), Ok(1));
+    assert_eq!(Price::zero().to_arrow_decimal128(1, 0, Down), Ok(0));
+    assert_eq!(
+        Price::max().to_arrow_decimal128(20, 8, Down),
+        Ok(u64::MAX as i128)
+    );
+}
+
+#[test]
+fn test_to_decimal128_rounding() {
+    let v = p("-2.345");
+    assert_eq!(v.to_arrow_decimal128(5, 2, Down), Ok(-234));
+    assert_eq!(v.to_arrow_decimal128(5, 2, HalfEven), Ok(-234));
+    assert_eq!(v.to_arrow_decimal128(5, 2, HalfUp), Ok(-235));
+    assert_eq!(v.to_arrow_decimal128(5, 2, Floor), Ok(-235));
+    assert_eq!(v.to_arrow_decimal128(5, 0, Ceiling), Ok(-2));
+    assert_eq!(p("0.00000001").to_arrow_decimal128(5, 0, Up), Ok(1));
+}
+
+#[test]
+fn test_to_decimal128_errors() {
+    assert_eq!(
+        p("123.45").to_arrow_decimal128(4, 2, Down),
+        Err(TryFromNumberError::Overflow)
+    );
+    assert_eq!(
+        p("-99.995").to_arrow_decimal128(4, 2, HalfUp),
+        Err(TryFromNumberError::Overflow)
+    );
+    assert_eq!(p("-99.995").to_arrow_decimal128(4, 2, Down), Ok(-9999));
+    assert_eq!(
+        p("1").to_arrow_decimal128(38, 38, Down),
+        Err(TryFromNumberError::Overflow)
+    );
+    assert_eq!(
+        p("1").to_arrow_decimal128(38, 127, Down),
+        Err(TryFromNumberError::Overflow)
+    );
+    assert_eq!(
+        Price::nan().to_arrow_decimal128(10, 2, Down),
+        Err(TryFromNumberError::Special)
+    );
+    assert_eq!(
+        Price::neg_infinity().to_arrow_decimal256(10, 2, Down),
+        Err(TryFromNumberError::Special)
+    );
+}
+
+#[test]
+#[should_panic(expected = "precision must lie in the range 1..=38")]
+fn test_to_decimal128_invalid_precision() {
+    let _ = p("1").to_arrow_decimal128(39, 2, Down);
+}
+
+#[test]
+fn test_to_decimal256() {
+    assert_eq!(
+        p("-123.45").to_arrow_decimal256(10, 2, Down),
+        Ok(i256::from_i128(-12345))
+    );
+    let max = i256::from_string(
+        "9999999999999999999999999999999999999999999999999999999999999999999999999999",
+    )
+    .unwrap();
+    let v = Big::from_str(
+        "99999999999999999999999999999999999999999999999999999999.99999999999999999999",
+    )
+    .unwrap();
+    assert_eq!(v.to_arrow_decimal256(76, 20, Down), Ok(max));
+    assert_eq!(
+        v.to_arrow_decimal256(76, 19, Down),
+        Ok(max / i256::from_i128(10))
+    );
+    assert_eq!((-v).to_arrow_decimal256(76, 20, Down), Ok(-max));
+    assert_eq!(
+        v.to_arrow_decimal256(75, 19, Up),
+        Err(TryFromNumberError::Overflow)
+    );
+    assert_eq!(
+        (v + Big::ulp()).to_arrow_decimal256(76, 20, Down),
+        Err(TryFromNumberError::Overflow)
+    );
+    assert_eq!(
+        Big::max().to_arrow_decimal256(76, 0, Down),
+        Err(TryFromNumberError::Overflow)
+    );
+}
+
+#[test]
+fn test_from_decimal128() {
+    assert_eq!(
+        Price::from_arrow_decimal128(12345, 2, Down),
+        Ok(p("123.45"))
+    );
+    assert_eq!(
+        Price::from_arrow_decimal128(-12345, 2, Down),
+        Ok(p("-123.45"))
+    );
+    assert_eq!(
+        Price::from_arrow_decimal128(12345, -3, Down),
+        Ok(p("12345000"))
+    );
+    assert_eq!(Price::from_arrow_decimal128(0, 10, Up), Ok(Price::zero()));
+    assert_eq!(
+        Price::from_arrow_decimal128(-12345678850, 10, HalfEven),
+        Ok(p("-1.23456788"))
+    );
+    assert_eq!(
+        Price::from_arrow_decimal128(-12345678850, 10, HalfUp),
+        Ok(p("-1.23456789"))
+    );
+    assert_eq!(Price::from_arrow_decimal128(1, 38, Up), Ok(Price::ulp()));
+    assert_eq!(
+        Price::from_arrow_decimal128(u64::MAX as i128, 8, Down),
+        Ok(Price::max())
+    );
+    assert_eq!(
+        Price::from_arrow_decimal128(u64::MAX as i128 + 1, 8, Down),
+        Err(TryFromNumberError::Overflow)
+    );
+    assert_eq!(
+        Price::from_arrow_decimal128(1, -20, Down),
+        Err(TryFromNumberError::Overflow)
+    );
+    assert_eq!(
+        Small::from_arrow_decimal128(i128::MIN, 0, Down),
+        Err(TryFromNumberError::Overflow)
+    );
+    assert_eq!(
+        Small::from_arrow_decimal128(-65535, 2, Down),
+        Ok(Small::min())
+    );
+}
+
+#[test]
+fn test_from_decimal256() {
+    let max = i256::from_string(
+        "9999999999999999999999999999999999999999999999999999999999999999999999999999",
+    )
+    .unwrap();
+    let v = Big::from_str(
+        "99999999999999999999999999999999999999999999999999999999.99999999999999999999",
+    )
+    .unwrap();
+    assert_eq!(Big::from_arrow_decimal256(max, 20, Down), Ok(v));
+    assert_eq!(Big::from_arrow_decimal256(-max, 20, Down), Ok(-v));
+    assert_eq!(
+        Big::from_arrow_decimal256(i256::MIN, 0, Down).map(|v| v.to_string()),
+        Ok(
+            "-57896044618658097711785492504343953926634992332820282019728792003956564819968"
+                .to_string()
+        )
+    );
+    assert_eq!(
+        Price::from_arrow_decimal256(i256::MAX, 0, Down),
+        Err(TryFromNumberError::Overflow)
+    );
+    assert_eq!(
+        Price::from_arrow_decimal256(i256::from_i128(-15), 9, Floor),
+        Ok(p("-0.00000002"))
+    );
+}
+
+#[test]
+fn test_round_trip() {
+    for v in &[
+        p("0.00000001"),
+        p("-123456.78901234"),
+        Price::max(),
+        Price::min(),
+    ] {
+        let d = v.to_arrow_decimal128(38, 8, Down).unwrap();
+        assert_eq!(Price::from_arrow_decimal128(d, 8, Down), Ok(*v));
+        let d = v.to_arrow_decimal256(76, 30, Down).unwrap();
+        assert_eq!(Price::from_arrow_decimal256(d, 30, Down), Ok(*v));
+    }
+    for v in &[Small::max(), Small::min(), Small::ulp()] {
+        let d = v.to_arrow_decimal128(5, 2, Down).unwrap();
+        assert_eq!(Small::from_arrow_decimal128(d, 2, Down), Ok(*v));
+    }
+}
@@ -0,0 +1,165 @@
+#[macro_use]
+extern crate fdec;
+
+fdec64! {
+    module price,
+    name Price,
+    length 1,
+    scale 8
+}
+
+fdec64! {
+    module notional,
+    name Notional,
+    length 4,
+    scale 18
+}
+
+fdec8! {
+    module small,
+    name Small,
+    length 4,
+    scale 2
+}
+
+fdec32! {
+    module int,
+    name Int,
+    length 3
+}
+
+fdec_convert!(
+    Price => Notional,
+    Notional => Price,
+    Price => Small,
+    Small => Int,
+);
+
+use fdec::{Number, RoundingMode, TryFromNumberError};
+use int::Int;
+use notional::Notional;
+use price::Price;
+use small::Small;
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+fn p(s: &str) -> Price {
+    Price::from_str(s).unwrap()
+}
+
+fn n(s: &str) -> Notional {
+    Notional::from_str(s).unwrap()
+}
+
+fn sm(s: &str) -> Small {
+    Small::from_str(s).unwrap()
+}
+
+#[test]
+fn test_widening() {
+    assert_eq!(p("0").to_number_exact::<Notional>(), Ok(Notional::zero()));
+    assert_eq!(p("-1.5").to_number_exact(), Ok(n("-1.5")));
+    assert_eq!(Price::ulp().to_number_exact(), Ok(n("0.00000001")));
+    assert_eq!(
+        Price::max().to_number_exact(),
+        Ok(n("184467440737.09551615"))
+    );
+    assert_eq!(
+        Price::min().to_number_exact(),
+        Ok(-n("184467440737.09551615"))
+    );
+    assert_eq!(Notional::try_from(p("123.45")), Ok(n("123.45")));
+}
+
+#[test]
+fn test_narrowing_exact() {
+    assert_eq!(n("-123.45").to_number_exact(), Ok(p("-123.45")));
+    assert_eq!(Price::try_from(n("0.00000001")), Ok(Price::ulp()));
+    assert_eq!(
+        Price::try_from(n("0.000000001")),
+        Err(TryFromNumberError::Fraction)
+    );
+    assert_eq!(
+        Price::try_from(n("184467440737.09551616")),
+        Err(TryFromNumberError::Overflow)
+    );
+    assert_eq!(
+        Price::try_from(n("-1000000000000")),
+        Err(TryFromNumberError::Overflow)
+    );
+    assert_eq!(Small::try_from(p("42949672.95")), Ok(Small::max()));
+    assert_eq!(
+        Small::try_from(p("42949672.96")),
+        Err(TryFromNumberError::Overflow)
+    );
+    assert_eq!(Int::try_from(sm("-42")), Ok(Int::from(-42)));
+    assert_eq!(Int::try_from(sm("0.5")), Err(TryFromNumberError::Fraction));
+}
+
+#[test]
+fn test_narrowing_with_rounding() {
+    use fdec::RoundingMode::*;
+
+    let v = n("1.234567894999999999");
+    assert_eq!(v.to_number(Down), Ok(p("1.23456789")));
+    assert_eq!(v.to_number(Up), Ok(p("1.2345679")));
+    assert_eq!(v.to_number(HalfUp), Ok(p("1.23456789")));
+    assert_eq!((-v).to_number(Floor), Ok(p("-1.2345679")));
+    assert_eq!((-v).to_number(Ceiling), Ok(p("-1.23456789")));
+
+    let tie = n("0.000000025");
+    assert_eq!(tie.to_number(HalfEven), Ok(p("0.00000002")));
+    assert_eq!(tie.to_number(HalfUp), Ok(p("0.00000003")));
+    assert_eq!(tie.to_number(HalfDown), Ok(p("0.00000002")));
+    assert_eq!((-tie).to_number(HalfUp), Ok(p("-0.00000003")));
+
+    // Rounding to zero doesn't produce negative zero
+    let z: Price = n("-0.000000001").to_number(Ceiling).unwrap();
+    assert!(z.is_zero());
+    assert!(z.is_sign_positive());
+
+    // Rounding may push the value out of the target range
+    assert_eq!(
+        n("184467440737.095516151").to_number::<Price>(Up),
+        Err(TryFromNumberError::Overflow)
+    );
+    assert_eq!(
+        n("184467440737.095516151").to_number(Down),
+        Ok(Price::max())
+    );
+    assert_eq!(sm("-2.5").to_number(HalfEven), Ok(Int::from(-2)));
+    assert_eq!(sm("-2.5").to_number(HalfUp), Ok(Int::from(-3)));
+}
+
+#[test]
+fn test_special_values() {
+    assert!(Price::nan().to_number_exact::<Notional>().unwrap().is_nan());
+    assert!(Notional::nan()
+        .to_number::<Small>(RoundingMode::Down)
+        .unwrap()
+        .is_nan());
+    assert_eq!(Price::try_from(Notional::infinity()), Ok(Price::infinity()));
+    assert_eq!(
+        Notional::neg_infinity().to_number(RoundingMode::Up),
+        Ok(Int::neg_infinity())
+    );
+}
+
+#[test]
+fn test_generic_conversion() {
+    fn total<A: Number, B: Number>(values: &[A]) -> Result<B, TryFromNumberError> {
+        let mut sum = B::zero();
+        for v in values {
+            sum = sum + v.to_number(RoundingMode::HalfEven)?;
+        }
+        Ok(sum)
+    }
+    assert_eq!(
+        total::<Notional, Price>(&[n("0.125000001"), n("0.125000001")]),
+        Ok(p("0.25"))
+    );
+    assert_eq!(
+        total::<Price, Small>(&[p("1.005"), p("1.015")]),
+        Ok(sm("2.02"))
+    );
+}
@@ -0,0 +1,541 @@
+#![cfg(any(
+    feature = "bincode",
+    feature = "borsh",
+    feature = "bytemuck",
+    feature = "rkyv",
+    feature = "zerocopy"
+))]
+
+#[cfg(feature = "bincode")]
+extern crate bincode2;
+#[cfg(feature = "borsh")]
+extern crate borsh;
+#[cfg(feature = "bytemuck")]
+extern crate bytemuck;
+#[macro_use]
+extern crate fdec;
+#[cfg(feature = "rkyv")]
+extern crate rkyv;
+#[cfg(feature = "zerocopy")]
+extern crate zerocopy;
+
+fdec8! {
+    module d8,
+    name Dec8,
+    length 2,
+    scale 2
+}
+
+fdec16! {
+    module d16,
+    name Dec16,
+    length 3,
+    scale 4
+}
+
+fdec32! {
+    module d32,
+    name Dec32,
+    length 3,
+    scale 6
+}
+
+fdec64! {
+    module d64,
+    name Dec64,
+    length 2,
+    scale 8
+}
+
+use d16::Dec16;
+use d32::Dec32;
+use d64::Dec64;
+use d8::Dec8;
+use fdec::Number;
+
+// Flag bytes that no encoding must accept: NaN together with infinity, and unsupported bits.
+const INVALID_FLAGS: [u8; 8] = [6, 7, 8, 0x10, 0x20, 0x40, 0x80, 0xff];
+
+// Calls `$check!(type, unit, units)` for each test type, where `units` is the number of units in its
+// in-memory layout (the magnitude followed by the flags).
+macro_rules! for_each_type {
+    ($check:ident) => {
+        $check!(Dec8, u8, 3);
+        $check!(Dec16, u16, 4);
+        $check!(Dec32, u32, 4);
+        $check!(Dec64, u64, 3);
+    };
+}
+
+// Calls `$check!(type, value)` for the values that every encoding must preserve.
+macro_rules! for_each_value {
+    ($check:ident) => {
+        for_each_value!(@values $check, Dec8);
+        for_each_value!(@values $check, Dec16);
+        for_each_value!(@values $check, Dec32);
+        for_each_value!(@values $check, Dec64);
+    };
+    (@values $check:ident, $t:ident) => {
+        for v in &[
+            $t::zero(),
+            $t::ulp(),
+            -$t::one(),
+            $t::max(),
+            $t::min(),
+            $t::nan(),
+            $t::infinity(),
+            $t::neg_infinity(),
+        ] {
+            $check!($t, *v);
+        }
+    };
+}
+
+#[cfg(feature = "bytemuck")]
+mod with_bytemuck {
+    use super::*;
+    use bytemuck::checked::{self, CheckedCastError};
+    use bytemuck::{PodCastError, Zeroable};
+    use std::mem::{align_of, size_of};
+    use std::str::FromStr;
+
+    #[test]
+    fn test_layout() {
+        assert_eq!((size_of::<Dec8>(), align_of::<Dec8>()), (3, 1));
+        assert_eq!((size_of::<Dec16>(), align_of::<Dec16>()), (8, 2));
+        assert_eq!((size_of::<Dec32>(), align_of::<Dec32>()), (16, 4));
+        assert_eq!((size_of::<Dec64>(), align_of::<Dec64>()), (24, 8));
+        assert_eq!(
+            bytemuck::cast::<Dec64, [u64; 3]>(Dec64::from_str("-1.5").unwrap()),
+            [150_000_000, 0, 1]
+        );
+        assert_eq!(bytemuck::cast::<Dec8, [u8; 3]>(Dec8::nan()), [0, 0, 2]);
+    }
+
+    #[cfg(target_endian = "little")]
+    #[test]
+    fn test_layout_matches_le_bytes() {
+        macro_rules! check {
+            ($t:ident, $v:expr) => {
+                let bytes = $v.to_le_bytes();
+                assert_eq!(&bytemuck::bytes_of(&$v)[..bytes.len()], &bytes[..]);
+            };
+        }
+        for_each_value!(check);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        macro_rules! check {
+            ($t:ident, $v:expr) => {
+                let restored = checked::try_from_bytes::<$t>(bytemuck::bytes_of(&$v)).unwrap();
+                assert_eq!(restored.to_le_bytes(), $v.to_le_bytes());
+            };
+        }
+        for_each_value!(check);
+    }
+
+    #[test]
+    fn test_zeroed() {
+        assert_eq!(Dec8::zeroed(), Dec8::zero());
+        assert_eq!(Dec16::zeroed(), Dec16::zero());
+        assert_eq!(Dec32::zeroed(), Dec32::zero());
+        assert_eq!(Dec64::zeroed(), Dec64::zero());
+    }
+
+    #[test]
+    fn test_misaligned_and_truncated() {
+        let values = [Dec64::one(), -Dec64::ulp()];
+        let bytes: &[u8] = bytemuck::cast_slice(&values);
+        assert_eq!(checked::try_cast_slice::<u8, Dec64>(bytes), Ok(&values[..]));
+        assert_eq!(
+            checked::try_from_bytes::<Dec64>(&bytes[1..25]).err(),
+            Some(CheckedCastError::PodCastError(
+                PodCastError::TargetAlignmentGreaterAndInputNotAligned
+            ))
+        );
+        assert_eq!(
+            checked::try_from_bytes::<Dec64>(&bytes[..23]).err(),
+            Some(CheckedCastError::PodCastError(PodCastError::SizeMismatch))
+        );
+        assert_eq!(
+            checked::try_cast_slice::<u8, Dec64>(&bytes[..47]).err(),
+            Some(CheckedCastError::PodCastError(
+                PodCastError::OutputSliceWouldHaveSlop
+            ))
+        );
+    }
+
+    #[test]
+    fn test_invalid_bit_patterns() {
+        macro_rules! check {
+            ($t:ident, $unit:ident, $n:expr) => {
+                let invalid = |units: [$unit; $n]| {
+                    assert_eq!(
+                        checked::try_cast::<[$unit; $n], $t>(units).err(),
+                        Some(CheckedCastError::InvalidBitPattern),
+                        "{:?}",
+                        units
+                    );
+                };
+                let mut units: [$unit; $n] = [0; $n];
+                for flags in &INVALID_FLAGS {
+                    units[$n - 1] = *flags as $unit;
+                    invalid(units);
+                }
+                // Negative NaN, which is never produced by the arithmetic
+                units[$n - 1] = 3;
+                invalid(units);
+                // Negative zero
+                units[$n - 1] = 1;
+                invalid(units);
+                // Special values with non-zero magnitude
+                units[0] = 1;
+                for flags in &[2, 4, 5] {
+                    units[$n - 1] = *flags;
+                    invalid(units);
+                }
+                // Valid patterns
+                for flags in &[0, 1] {
+                    units[$n - 1] = *flags;
+                    assert!(checked::try_cast::<[$unit; $n], $t>(units).is_ok());
+                }
+            };
+        }
+        for_each_type!(check);
+        // Flags outside of the lowest byte
+        assert_eq!(
+            checked::try_cast::<[u16; 4], Dec16>([1, 0, 0, 0x100]).err(),
+            Some(CheckedCastError::InvalidBitPattern)
+        );
+        assert_eq!(
+            checked::try_cast::<[u64; 3], Dec64>([1, 0, 1 << 40]).err(),
+            Some(CheckedCastError::InvalidBitPattern)
+        );
+    }
+}
+
+#[cfg(feature = "zerocopy")]
+mod with_zerocopy {
+    use super::*;
+    use fdec::FromBytesError;
+    use std::str::FromStr;
+    use zerocopy::{Immutable, IntoBytes, KnownLayout};
+
+    fn assert_derived<T: IntoBytes + Immutable + KnownLayout>() {}
+
+    #[test]
+    fn test_as_bytes() {
+        assert_derived::<Dec8>();
+        assert_derived::<Dec64>();
+        assert_eq!(Dec8::from_str("-0.01").unwrap().as_bytes(), &[1, 0, 1]);
+        assert_eq!(Dec8::infinity().as_bytes(), &[0, 0, 4]);
+        assert_eq!(Dec16::zero().as_bytes(), &[0; 8]);
+        assert_eq!(Dec32::nan().as_bytes().len(), 16);
+        assert_eq!(Dec64::max().as_bytes().len(), 24);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        macro_rules! check {
+            ($t:ident, $v:expr) => {
+                let read = $t::try_read_from_bytes($v.as_bytes()).unwrap();
+                assert_eq!(read.to_le_bytes(), $v.to_le_bytes());
+                let referenced = $t::try_ref_from_bytes($v.as_bytes()).unwrap();
+                assert_eq!(referenced.to_le_bytes(), $v.to_le_bytes());
+            };
+        }
+        for_each_value!(check);
+    }
+
+    #[test]
+    fn test_misaligned_input() {
+        let v = Dec64::from_str("-12345.6789").unwrap();
+        let mut buf = [0u64; 4];
+        let bytes = buf.as_mut_bytes();
+        bytes[1..25].copy_from_slice(v.as_bytes());
+        assert_eq!(Dec64::try_read_from_bytes(&bytes[1..25]), Ok(v));
+        assert_eq!(
+            Dec64::try_ref_from_bytes(&bytes[1..25]),
+            Err(FromBytesError::InvalidFormat)
+        );
+        bytes[..24].copy_from_slice(v.as_bytes());
+        assert_eq!(Dec64::try_ref_from_bytes(&bytes[..24]), Ok(&v));
+    }
+
+    #[test]
+    fn test_wrong_length() {
+        macro_rules! check {
+            ($t:ident, $unit:ident, $n:expr) => {
+                let units: [$unit; $n + 1] = [0; $n + 1];
+                let bytes = units.as_bytes();
+                let len = ::std::mem::size_of::<$t>();
+                for range in &[0..0, 0..len - 1, 0..len + ::std::mem::size_of::<$unit>()] {
+                    assert_eq!(
+                        $t::try_read_from_bytes(&bytes[range.clone()]),
+                        Err(FromBytesError::InvalidFormat)
+                    );
+                    assert_eq!(
+                        $t::try_ref_from_bytes(&bytes[range.clone()]),
+                        Err(FromBytesError::InvalidFormat)
+                    );
+                }
+            };
+        }
+        for_each_type!(check);
+    }
+
+    #[test]
+    fn test_invalid_flags() {
+        macro_rules! check {
+            ($t:ident, $unit:ident, $n:expr) => {
+                let invalid = |units: [$unit; $n]| {
+                    assert_eq!(
+                        $t::try_read_from_bytes(units.as_bytes()),
+                        Err(FromBytesError::InvalidFlags),
+                        "{:?}",
+                        units
+                    );
+                    assert_eq!(
+                        $t::try_ref_from_bytes(units.as_bytes()),
+                        Err(FromBytesError::InvalidFlags),
+                        "{:?}",
+                        units
+                    );
+                };
+                let mut units: [$unit; $n] = [0; $n];
+                for flags in &INVALID_FLAGS {
+                    units[$n - 1] = *flags as $unit;
+                    invalid(units);
+                }
+                // Negative NaN, which is never produced by the arithmetic
+                units[$n - 1] = 3;
+                invalid(units);
+                // Negative zero
+                units[$n - 1] = 1;
+                invalid(units);
+                // Special values with non-zero magnitude
+                units[0] = 1;
+                for flags in &[2, 4, 5] {
+                    units[$n - 1] = *flags;
+                    invalid(units);
+                }
+            };
+        }
+        for_each_type!(check);
+        // Flags outside of the lowest byte
+        let mut bytes = Dec64::one().as_bytes().to_vec();
+        bytes[20] = 1;
+        assert_eq!(
+            Dec64::try_read_from_bytes(&bytes),
+            Err(FromBytesError::InvalidFlags)
+        );
+    }
+}
+
+#[cfg(feature = "rkyv")]
+mod with_rkyv {
+    use super::*;
+    use rkyv::rancor::Error;
+
+    #[test]
+    fn test_round_trip() {
+        macro_rules! check {
+            ($t:ident, $v:expr) => {
+                let bytes = rkyv::to_bytes::<Error>(&$v).unwrap();
+                assert_eq!(&bytes[..], &$v.to_le_bytes()[..]);
+                let archived = rkyv::access::<rkyv::Archived<$t>, Error>(&bytes).unwrap();
+                assert_eq!(*archived, $v.to_le_bytes());
+                let restored = rkyv::deserialize::<$t, Error>(archived).unwrap();
+                assert_eq!(restored.to_le_bytes(), $v.to_le_bytes());
+            };
+        }
+        for_each_value!(check);
+    }
+
+    #[test]
+    fn test_vec_round_trip() {
+        let values = vec![Dec32::one(), -Dec32::ulp(), Dec32::max(), Dec32::infinity()];
+        let bytes = rkyv::to_bytes::<Error>(&values).unwrap();
+        let archived = rkyv::access::<rkyv::Archived<Vec<Dec32>>, Error>(&bytes).unwrap();
+        assert_eq!(archived[1], (-Dec32::ulp()).to_le_bytes());
+        assert_eq!(
+            rkyv::from_bytes::<Vec<Dec32>, Error>(&bytes).unwrap(),
+            values
+        );
+    }
+
+    #[test]
+    fn test_truncated_buffer() {
+        macro_rules! check {
+            ($t:ident, $unit:ident, $n:expr) => {
+                let bytes = $t::one().to_le_bytes();
+                for len in 0..$t::BYTE_LEN {
+                    assert!(rkyv::from_bytes::<$t, Error>(&bytes[..len]).is_err());
+                }
+            };
+        }
+        for_each_type!(check);
+    }
+
+    #[test]
+    fn test_invalid_flags() {
+        macro_rules! check {
+            ($t:ident, $unit:ident, $n:expr) => {
+                let mut bytes = $t::one().to_le_bytes();
+                for flags in &INVALID_FLAGS {
+                    bytes[$t::BYTE_LEN - 1] = *flags;
+                    assert!(rkyv::from_bytes::<$t, Error>(&bytes).is_err());
+                }
+            };
+        }
+        for_each_type!(check);
+    }
+}
+
+#[cfg(feature = "borsh")]
+mod with_borsh {
+    use super::*;
+    use std::io::ErrorKind;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_round_trip() {
+        macro_rules! check {
+            ($t:ident, $v:expr) => {
+                let bytes = borsh::to_vec(&$v).unwrap();
+                assert_eq!(&bytes[..], &$v.to_le_bytes()[..]);
+                let restored = borsh::from_slice::<$t>(&bytes).unwrap();
+                assert_eq!(restored.to_le_bytes(), $v.to_le_bytes());
+            };
+        }
+        for_each_value!(check);
+    }
+
+    #[test]
+    fn test_tuple_round_trip() {
+        let v = (
+            7u32,
+            Dec64::from_str("-98765.4321").unwrap(),
+            vec![Dec16::one(), Dec16::ulp()],
+        );
+        let bytes = borsh::to_vec(&v).unwrap();
+        assert_eq!(bytes.len(), 4 + Dec64::BYTE_LEN + 4 + 2 * Dec16::BYTE_LEN);
+        assert_eq!(
+            borsh::from_slice::<(u32, Dec64, Vec<Dec16>)>(&bytes).unwrap(),
+            v
+        );
+    }
+
+    #[test]
+    fn test_truncated_buffer() {
+        macro_rules! check {
+            ($t:ident, $unit:ident, $n:expr) => {
+                let bytes = $t::one().to_le_bytes();
+                for len in 0..$t::BYTE_LEN {
+                    let e = borsh::from_slice::<$t>(&bytes[..len]).unwrap_err();
+                    assert_eq!(e.kind(), ErrorKind::UnexpectedEof);
+                }
+                let mut bytes = bytes.to_vec();
+                bytes.push(0);
+                let e = borsh::from_slice::<$t>(&bytes).unwrap_err();
+                assert_eq!(e.kind(), ErrorKind::InvalidData);
+            };
+        }
+        for_each_type!(check);
+    }
+
+    #[test]
+    fn test_invalid_flags() {
+        macro_rules! check {
+            ($t:ident, $unit:ident, $n:expr) => {
+                let mut bytes = $t::one().to_le_bytes();
+                for flags in &INVALID_FLAGS {
+                    bytes[$t::BYTE_LEN - 1] = *flags;
+                    let e = borsh::from_slice::<$t>(&bytes).unwrap_err();
+                    assert_eq!(e.kind(), ErrorKind::InvalidData);
+                }
+            };
+        }
+        for_each_type!(check);
+    }
+}
+
+#[cfg(feature = "bincode")]
+mod with_bincode {
+    use super::*;
+    use bincode2::config;
+    use bincode2::error::DecodeError;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_round_trip() {
+        macro_rules! check {
+            ($t:ident, $v:expr) => {
+                for bytes in &[
+                    bincode2::encode_to_vec($v, config::standard()).unwrap(),
+                    bincode2::encode_to_vec($v, config::legacy()).unwrap(),
+                ] {
+                    assert_eq!(&bytes[..], &$v.to_le_bytes()[..]);
+                    let (restored, len) =
+                        bincode2::decode_from_slice::<$t, _>(bytes, config::standard()).unwrap();
+                    assert_eq!(restored.to_le_bytes(), $v.to_le_bytes());
+                    assert_eq!(len, $t::BYTE_LEN);
+                    let (restored, _) =
+                        bincode2::borrow_decode_from_slice::<$t, _>(bytes, config::standard())
+                            .unwrap();
+                    assert_eq!(restored.to_le_bytes(), $v.to_le_bytes());
+                }
+            };
+        }
+        for_each_value!(check);
+    }
+
+    #[test]
+    fn test_tuple_round_trip() {
+        let v = (
+            7u32,
+            Dec64::from_str("-98765.4321").unwrap(),
+            vec![Dec16::one(), Dec16::ulp()],
+        );
+        let bytes = bincode2::encode_to_vec(&v, config::standard()).unwrap();
+        let (restored, len) =
+            bincode2::decode_from_slice::<(u32, Dec64, Vec<Dec16>), _>(&bytes, config::standard())
+                .unwrap();
+        assert_eq!(restored, v);
+        assert_eq!(len, bytes.len());
+    }
+
+    #[test]
+    fn test_truncated_buffer() {
+        macro_rules! check {
+            ($t:ident, $unit:ident, $n:expr) => {
+                let bytes = $t::one().to_le_bytes();
+                for len in 0..$t::BYTE_LEN {
+                    match bincode2::decode_from_slice::<$t, _>(&bytes[..len], config::standard()) {
+                        Err(DecodeError::UnexpectedEnd { .. }) => {}
+                        r => panic!("unexpected result: {:?}", r),
+                    }
+                }
+            };
+        }
+        for_each_type!(check);
+    }
+
+    #[test]
+    fn test_invalid_flags() {
+        macro_rules! check {
+            ($t:ident, $unit:ident, $n:expr) => {
+                let mut bytes = $t::one().to_le_bytes();
+                for flags in &INVALID_FLAGS {
+                    bytes[$t::BYTE_LEN - 1] = *flags;
+                    match bincode2::decode_from_slice::<$t, _>(&bytes, config::standard()) {
+                        Err(DecodeError::Other(msg)) => assert_eq!(msg, "invalid number flags"),
+                        r => panic!("unexpected result: {:?}", r),
+                    }
+                }
+            };
+        }
+        for_each_type!(check);
+    }
+}
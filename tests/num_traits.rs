@@ -0,0 +1,172 @@
+#![cfg(feature = "num-traits")]
+
+#[macro_use]
+extern crate fdec;
+extern crate num_traits;
+
+fdec32! {
+    module dec,
+    name Decimal,
+    length 3,
+    scale 6
+}
+
+fdec8! {
+    module small,
+    name Small,
+    length 2,
+    scale 1
+}
+
+use dec::Decimal;
+use fdec::{Number, ParseNumberError};
+use num_traits::*;
+use small::Small;
+use std::str::FromStr;
+
+fn d(s: &str) -> Decimal {
+    Decimal::from_str(s).unwrap()
+}
+
+fn sm(s: &str) -> Small {
+    Small::from_str(s).unwrap()
+}
+
+#[test]
+fn test_identities() {
+    assert_eq!(<Decimal as Zero>::zero(), d("0"));
+    assert!(Zero::is_zero(&d("0.000000")));
+    assert!(!Zero::is_zero(&d("0.000001")));
+    assert_eq!(<Decimal as One>::one(), d("1"));
+    assert_eq!(Decimal::min_value(), Number::min());
+    assert_eq!(Decimal::max_value(), Number::max());
+    assert_eq!(Small::max_value(), sm("6553.5"));
+}
+
+#[test]
+fn test_from_str_radix() {
+    assert_eq!(Decimal::from_str_radix("-12.5", 10), Ok(d("-12.5")));
+    assert_eq!(Decimal::from_str_radix("ff", 16), Ok(d("255")));
+    assert_eq!(Decimal::from_str_radix("-101", 2), Ok(d("-5")));
+    assert_eq!(Decimal::from_str_radix("+Zz", 36), Ok(d("1295")));
+    assert_eq!(
+        Decimal::from_str_radix("1.5", 16),
+        Err(ParseNumberError::InvalidFormat)
+    );
+    assert_eq!(
+        Decimal::from_str_radix("-", 8),
+        Err(ParseNumberError::InvalidFormat)
+    );
+    assert_eq!(
+        Small::from_str_radix("ffff", 16),
+        Err(ParseNumberError::Overflow)
+    );
+    assert_eq!(Small::from_str_radix("1999", 16), Ok(sm("6553")));
+}
+
+#[test]
+fn test_signed() {
+    assert_eq!(Signed::abs(&d("-1.5")), d("1.5"));
+    assert_eq!(d("3").abs_sub(&d("1.25")), d("1.75"));
+    assert_eq!(d("1").abs_sub(&d("1.25")), d("0"));
+    assert_eq!(d("-0.5").signum(), d("-1"));
+    assert_eq!(d("0.5").signum(), d("1"));
+    assert_eq!(d("0").signum(), d("0"));
+    assert!(Decimal::nan().signum().is_nan());
+    assert_eq!(Decimal::neg_infinity().signum(), d("-1"));
+    assert!(d("0.000001").is_positive());
+    assert!(!d("0").is_positive());
+    assert!(!d("0").is_negative());
+    assert!(d("-7").is_negative());
+    assert!(!Decimal::nan().is_positive());
+    assert!(!Decimal::nan().is_negative());
+}
+
+#[test]
+fn test_to_primitive() {
+    assert_eq!(ToPrimitive::to_i64(&d("-12.9")), Some(-12));
+    assert_eq!(ToPrimitive::to_u8(&d("255.5")), Some(255));
+    assert_eq!(ToPrimitive::to_u8(&d("256")), None);
+    assert_eq!(ToPrimitive::to_u32(&d("-1")), None);
+    assert_eq!(ToPrimitive::to_i128(&Decimal::nan()), None);
+    assert_eq!(ToPrimitive::to_f64(&d("-0.25")), Some(-0.25));
+    assert_eq!(ToPrimitive::to_f32(&d("1.5")), Some(1.5));
+    assert!(ToPrimitive::to_f64(&Decimal::nan()).unwrap().is_nan());
+}
+
+#[test]
+fn test_from_primitive() {
+    assert_eq!(Decimal::from_i32(-42), Some(d("-42")));
+    assert_eq!(
+        Decimal::from_u128(79228162514264337593543),
+        Some(d("79228162514264337593543"))
+    );
+    assert_eq!(Decimal::from_u128(79228162514264337593544), None);
+    assert_eq!(Small::from_i8(-128), Some(sm("-128")));
+    assert_eq!(Small::from_u16(6554), None);
+    assert_eq!(Decimal::from_f64(0.125), Some(d("0.125")));
+    assert_eq!(Decimal::from_f32(-2.5), Some(d("-2.5")));
+    assert_eq!(Decimal::from_f64(1e30), None);
+    assert_eq!(Decimal::from_f64(f64::INFINITY), Some(Decimal::infinity()));
+    assert!(Decimal::from_f64(f64::NAN).unwrap().is_nan());
+}
+
+#[test]
+fn test_num_cast() {
+    assert_eq!(<Decimal as NumCast>::from(-7_i8), Some(d("-7")));
+    assert_eq!(
+        <Decimal as NumCast>::from(u64::MAX),
+        Some(d("18446744073709551615"))
+    );
+    assert_eq!(<Decimal as NumCast>::from(0.5_f64), Some(d("0.5")));
+    assert_eq!(<Decimal as NumCast>::from(u128::MAX), None);
+    assert_eq!(<Small as NumCast>::from(d("-12.5")), Some(sm("-12.5")));
+    assert_eq!(<Small as NumCast>::from(d("7000")), None);
+    assert_eq!(cast::<Decimal, i16>(d("-300.7")), Some(-300));
+    assert_eq!(cast::<Decimal, f64>(d("0.75")), Some(0.75));
+}
+
+#[test]
+fn test_checked_ops() {
+    let max: Decimal = Number::max();
+    assert_eq!(d("1.5").checked_add(&d("2")), Some(d("3.5")));
+    assert_eq!(max.checked_add(&d("0.000001")), None);
+    assert_eq!(d("1.5").checked_sub(&d("2")), Some(d("-0.5")));
+    assert_eq!((-max).checked_sub(&d("1")), None);
+    assert_eq!(d("1.5").checked_mul(&d("-2")), Some(d("-3")));
+    assert_eq!(max.checked_mul(&d("2")), None);
+    assert_eq!(d("1").checked_div(&d("8")), Some(d("0.125")));
+    assert_eq!(d("1").checked_div(&d("0")), None);
+    assert_eq!(Decimal::nan().checked_add(&d("1")), None);
+}
+
+#[test]
+fn test_pow() {
+    assert_eq!(Pow::pow(d("1.5"), 2), d("2.25"));
+    assert_eq!(Pow::pow(&d("2"), -3), d("0.125"));
+    assert_eq!(Pow::pow(d("7"), 0), d("1"));
+}
+
+#[test]
+fn test_generic_code() {
+    fn mean<T: Num + NumCast + Copy>(values: &[T]) -> Option<T> {
+        let count = T::from(values.len())?;
+        let sum = values.iter().fold(T::zero(), |acc, &v| acc + v);
+        Some(sum / count)
+    }
+    fn clamp_all<T: Signed + Bounded + PartialOrd + Copy>(values: &mut [T], limit: T) {
+        for v in values.iter_mut() {
+            if v.abs() > limit {
+                *v = limit * v.signum();
+            }
+        }
+    }
+
+    assert_eq!(mean(&[d("1"), d("2"), d("4")]), Some(d("2.333333")));
+    let mut values = [d("-5"), d("0.5"), d("3")];
+    clamp_all(&mut values, d("2"));
+    assert_eq!(values, [d("-2"), d("0.5"), d("2")]);
+    assert_eq!(num_traits::pow(d("1.1"), 3), d("1.331"));
+    assert_eq!(checked_pow(sm("80"), 2), Some(sm("6400")));
+    assert_eq!(checked_pow(sm("81"), 2), None);
+}
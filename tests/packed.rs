@@ -0,0 +1,143 @@
+#[macro_use]
+extern crate fdec;
+
+fdec8! {
+    module tiny,
+    name Tiny,
+    length 1,
+    scale 1,
+    packed PackedTiny
+}
+
+fdec16! {
+    module mid,
+    name Mid,
+    length 3,
+    scale 4,
+    packed PackedMid
+}
+
+fdec32! {
+    module big,
+    name Big,
+    length 4,
+    scale 10,
+    packed PackedBig
+}
+
+fdec64! {
+    module price,
+    name Price,
+    length 1,
+    scale 4,
+    wide module wide_price,
+    name WidePrice,
+    packed PackedPrice
+}
+
+use big::{Big, PackedBig};
+use fdec::{Number, RoundingMode, TryFromNumberError};
+use mid::{Mid, PackedMid};
+use price::{PackedPrice, Price};
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::mem::size_of;
+use std::str::FromStr;
+use tiny::{PackedTiny, Tiny};
+
+#[test]
+fn test_size() {
+    assert_eq!(size_of::<PackedTiny>(), 1);
+    assert_eq!(size_of::<Option<PackedTiny>>(), 1);
+    assert_eq!(size_of::<PackedMid>(), 6);
+    assert_eq!(size_of::<Option<PackedMid>>(), 6);
+    assert_eq!(size_of::<PackedBig>(), 16);
+    assert_eq!(size_of::<Option<PackedBig>>(), 16);
+    assert_eq!(size_of::<PackedPrice>(), 8);
+    assert_eq!(size_of::<Option<PackedPrice>>(), 8);
+}
+
+#[test]
+fn test_boundaries() {
+    // The largest magnitudes are 2^(bits - 3) - 1
+    let max = Tiny::from_str("3.1").unwrap();
+    assert_eq!(PackedTiny::try_from(max).unwrap().get(), max);
+    assert_eq!(PackedTiny::try_from(-max).unwrap().get(), -max);
+    assert_eq!(
+        PackedTiny::try_from(max + Tiny::ulp()),
+        Err(TryFromNumberError::Overflow)
+    );
+    assert_eq!(
+        PackedTiny::try_from(Tiny::min()),
+        Err(TryFromNumberError::Overflow)
+    );
+
+    let max = Mid::from_str("3518437208.8831").unwrap();
+    assert_eq!(PackedMid::try_from(max).unwrap().get(), max);
+    assert_eq!(
+        PackedMid::try_from(-max - Mid::ulp()),
+        Err(TryFromNumberError::Overflow)
+    );
+
+    let max = Big::from_str("4253529586511730793292182592.8971026431").unwrap();
+    assert_eq!(PackedBig::try_from(-max).unwrap().get(), -max);
+    assert_eq!(
+        PackedBig::try_from(max + Big::ulp()),
+        Err(TryFromNumberError::Overflow)
+    );
+
+    let max = Price::from_str("230584300921369.3951").unwrap();
+    assert_eq!(Price::from(PackedPrice::try_from(max).unwrap()), max);
+    assert_eq!(
+        PackedPrice::try_from(max + Price::ulp()),
+        Err(TryFromNumberError::Overflow)
+    );
+}
+
+#[test]
+fn test_specials() {
+    for v in &[Tiny::nan(), Tiny::infinity(), Tiny::neg_infinity()] {
+        let p = PackedTiny::try_from(*v).unwrap();
+        assert_eq!(p.to_string(), v.to_string());
+    }
+    assert!(PackedPrice::try_from(Price::nan()).unwrap().get().is_nan());
+    assert_eq!(
+        PackedBig::try_from(Big::neg_infinity()).unwrap().get(),
+        Big::neg_infinity()
+    );
+    // Packed values are compared by representation, so NaN is equal to itself
+    assert_eq!(
+        PackedMid::try_from(Mid::nan()),
+        PackedMid::try_from(Mid::nan())
+    );
+    // Negative zero is normalized
+    assert_eq!(
+        PackedMid::try_from(-Mid::zero()).unwrap(),
+        PackedMid::default()
+    );
+}
+
+#[test]
+fn test_default_and_hash() {
+    assert_eq!(PackedTiny::default().get(), Tiny::zero());
+    assert_eq!(PackedBig::default().get(), Big::zero());
+    let mut set = HashSet::new();
+    set.insert(PackedPrice::try_from(Price::one()).unwrap());
+    set.insert(PackedPrice::try_from(Price::from_str("1.0000").unwrap()).unwrap());
+    set.insert(PackedPrice::try_from(-Price::one()).unwrap());
+    assert_eq!(set.len(), 2);
+    let none: Option<PackedPrice> = None;
+    assert_eq!(none.map(PackedPrice::get), None);
+}
+
+#[test]
+fn test_with_wide() {
+    let a = Price::from_str("1234.5678").unwrap();
+    let p = a.widening_mul(a);
+    let v = Price::from_wide(p, RoundingMode::HalfEven).unwrap();
+    assert_eq!(PackedPrice::try_from(v).unwrap().get(), v);
+    assert_eq!(
+        format!("{:?}", PackedPrice::try_from(a).unwrap()),
+        format!("{:?}", a)
+    );
+}
@@ -0,0 +1,185 @@
+#![cfg(feature = "serde")]
+
+#[macro_use]
+extern crate fdec;
+#[macro_use]
+extern crate serde_derive;
+extern crate bincode;
+extern crate serde;
+extern crate serde_json;
+
+fdec64! {
+    module dec,
+    name Decimal,
+    length 2,
+    scale 10
+}
+
+fdec8! {
+    module small,
+    name Small,
+    length 3,
+    scale 2
+}
+
+use dec::*;
+use small::Small;
+use std::str::FromStr;
+
+fn d(s: &str) -> Decimal {
+    Decimal::from_str(s).unwrap()
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Record {
+    default: Decimal,
+    #[serde(with = "fdec::serde::string")]
+    string: Small,
+    #[serde(with = "fdec::serde::bytes")]
+    bytes: Decimal,
+}
+
+#[test]
+fn test_string_form() {
+    assert_eq!(serde_json::to_string(&d("-12.5")).unwrap(), "\"-12.5\"");
+    assert_eq!(
+        serde_json::to_string(&Decimal::max()).unwrap(),
+        format!("\"{}\"", Decimal::max())
+    );
+    assert_eq!(
+        serde_json::from_str::<Decimal>("\"0.0000000001\"").unwrap(),
+        Decimal::ulp()
+    );
+    assert_eq!(serde_json::from_str::<Decimal>("\"+7\"").unwrap(), d("7"));
+
+    for v in &[
+        Decimal::max(),
+        Decimal::min(),
+        Decimal::ulp(),
+        Decimal::zero(),
+        d("-3.25"),
+    ] {
+        let json = serde_json::to_string(v).unwrap();
+        assert_eq!(serde_json::from_str::<Decimal>(&json).unwrap(), *v);
+        let bin = bincode::serialize(v).unwrap();
+        assert_eq!(bincode::deserialize::<Decimal>(&bin).unwrap(), *v);
+    }
+}
+
+#[test]
+fn test_string_form_special_values() {
+    assert_eq!(serde_json::to_string(&Decimal::nan()).unwrap(), "\"NaN\"");
+    assert_eq!(
+        serde_json::to_string(&Decimal::infinity()).unwrap(),
+        "\"Infinity\""
+    );
+    assert_eq!(
+        serde_json::to_string(&Decimal::neg_infinity()).unwrap(),
+        "\"-Infinity\""
+    );
+    assert!(serde_json::from_str::<Decimal>("\"NaN\"").unwrap().is_nan());
+    assert_eq!(
+        serde_json::from_str::<Decimal>("\"Infinity\"").unwrap(),
+        Decimal::infinity()
+    );
+    assert_eq!(
+        serde_json::from_str::<Decimal>("\"-Infinity\"").unwrap(),
+        Decimal::neg_infinity()
+    );
+}
+
+#[test]
+fn test_string_form_errors() {
+    assert!(serde_json::from_str::<Decimal>("\"1.2.3\"").is_err());
+    assert!(serde_json::from_str::<Decimal>("\"\"").is_err());
+    assert!(serde_json::from_str::<Small>("\"167772.16\"").is_err());
+    // Numbers are not accepted by the string form
+    assert!(serde_json::from_str::<Decimal>("1.5").is_err());
+}
+
+#[test]
+fn test_bytes_form() {
+    let r = Record {
+        default: d("1.5"),
+        string: Small::from_str("-0.25").unwrap(),
+        bytes: d("-2.75"),
+    };
+    let bin = bincode::serialize(&r).unwrap();
+    let bytes = d("-2.75").to_be_bytes();
+    assert!(bin.ends_with(&bytes));
+    assert_eq!(bincode::deserialize::<Record>(&bin).unwrap(), r);
+
+    let json = serde_json::to_string(&r).unwrap();
+    assert!(json.starts_with("{\"default\":\"1.5\",\"string\":\"-0.25\",\"bytes\":[1,"));
+    assert_eq!(serde_json::from_str::<Record>(&json).unwrap(), r);
+
+    let special = Record {
+        default: Decimal::infinity(),
+        string: Small::neg_infinity(),
+        bytes: Decimal::neg_infinity(),
+    };
+    let bin = bincode::serialize(&special).unwrap();
+    assert_eq!(bincode::deserialize::<Record>(&bin).unwrap(), special);
+}
+
+#[test]
+fn test_bytes_form_errors() {
+    #[derive(Deserialize, Debug)]
+    struct Bytes(#[serde(with = "fdec::serde::bytes")] Small);
+
+    assert_eq!(
+        serde_json::from_str::<Bytes>("[0,1,2,3]").unwrap().0,
+        Small::from_le_units(false, [3, 2, 1])
+    );
+    assert!(serde_json::from_str::<Bytes>("[0,1,2]").is_err());
+    assert!(serde_json::from_str::<Bytes>("[0,1,2,3,4]").is_err());
+    // Invalid flags
+    assert!(serde_json::from_str::<Bytes>("[128,1,2,3]").is_err());
+}
+
+#[cfg(feature = "serde-arbitrary-precision")]
+mod number {
+    use super::*;
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Quote {
+        #[serde(with = "fdec::serde::number")]
+        price: Decimal,
+    }
+
+    #[test]
+    fn test_number_form() {
+        let q = Quote {
+            price: Decimal::max(),
+        };
+        let json = serde_json::to_string(&q).unwrap();
+        assert_eq!(json, format!("{{\"price\":{}}}", Decimal::max()));
+        assert_eq!(serde_json::from_str::<Quote>(&json).unwrap(), q);
+
+        assert_eq!(
+            serde_json::from_str::<Quote>("{\"price\":-0.0000000001}").unwrap(),
+            Quote {
+                price: -Decimal::ulp()
+            }
+        );
+        assert_eq!(
+            serde_json::from_str::<Quote>("{\"price\":42}").unwrap(),
+            Quote { price: d("42") }
+        );
+    }
+
+    #[test]
+    fn test_number_form_errors() {
+        assert!(serde_json::to_string(&Quote {
+            price: Decimal::nan()
+        })
+        .is_err());
+        assert!(serde_json::to_string(&Quote {
+            price: Decimal::infinity()
+        })
+        .is_err());
+        assert!(serde_json::from_str::<Quote>("{\"price\":\"1.5\"}").is_err());
+        assert!(serde_json::from_str::<Quote>("{\"price\":1e3}").is_err());
+        assert!(serde_json::from_str::<Quote>("{\"price\":1e100}").is_err());
+    }
+}
@@ -9,6 +9,7 @@ fdec16! {
 }
 
 use dec::*;
+use std::str::FromStr;
 
 #[test]
 fn test_from_unit_boundaries() {
@@ -23,3 +24,108 @@ fn test_from_unit_overflow() {
     assert_eq!(Dec::from(-66_i16), Dec::neg_infinity());
     assert_eq!(Dec::with_scale(-6601_i16, 2), Dec::neg_infinity());
 }
+
+#[test]
+fn test_decimal64() {
+    // -7500 * 10^-3
+    let v = Dec::from_str("-7.5").unwrap();
+    assert_eq!(
+        v.to_decimal64_dpd(RoundingMode::Down),
+        Ok(0xa22c_0000_0000_1e80)
+    );
+    assert_eq!(
+        Dec::from_decimal64_dpd(0xa22c_0000_0000_1e80, RoundingMode::Down),
+        Ok(v)
+    );
+    // 65 * 10^0
+    assert_eq!(
+        Dec::from_decimal64_bid(0x31c0_0000_0000_0041, RoundingMode::Down),
+        Ok(Dec::from(65))
+    );
+    // 1 * 10^2
+    assert_eq!(
+        Dec::from_decimal64_bid(0x3200_0000_0000_0001, RoundingMode::Down),
+        Err(TryFromNumberError::Overflow)
+    );
+}
+
+#[test]
+fn test_pg_numeric() {
+    assert_eq!(
+        Dec::max().to_pg_numeric(),
+        [0, 2, 0, 0, 0, 0, 0, 3, 0, 65, 0x14, 0xe6]
+    );
+    for v in &[Dec::max(), Dec::min(), Dec::ulp()] {
+        assert_eq!(
+            Dec::from_pg_numeric(&v.to_pg_numeric(), RoundingMode::Down),
+            Ok(*v)
+        );
+    }
+    // 100
+    assert_eq!(
+        Dec::from_pg_numeric(&[0, 1, 0, 0, 0, 0, 0, 0, 0, 100], RoundingMode::Down),
+        Err(FromBytesError::Overflow)
+    );
+}
+
+#[test]
+fn test_units_nanos() {
+    assert_eq!(
+        Dec::min().to_units_nanos(RoundingMode::Down),
+        Ok((-65, -535_000_000))
+    );
+    assert_eq!(
+        Dec::from_units_nanos(0, 1_500_000, RoundingMode::HalfEven),
+        Ok(Dec::from_str("0.002").unwrap())
+    );
+    assert_eq!(
+        Dec::from_units_nanos(0, -1_500_000, RoundingMode::Down),
+        Ok(Dec::from_str("-0.001").unwrap())
+    );
+    assert_eq!(
+        Dec::from_units_nanos(0, -1_500_000, RoundingMode::Floor),
+        Ok(Dec::from_str("-0.002").unwrap())
+    );
+    assert_eq!(
+        Dec::from_units_nanos(1, 500_000, RoundingMode::HalfEven),
+        Ok(Dec::from(1))
+    );
+    assert_eq!(
+        Dec::from_units_nanos(1, 500_001, RoundingMode::HalfEven),
+        Ok(Dec::from_str("1.001").unwrap())
+    );
+    assert_eq!(
+        Dec::from_units_nanos(66, 0, RoundingMode::Down),
+        Err(TryFromNumberError::Overflow)
+    );
+    assert_eq!(
+        Dec::from_units_nanos(65, 535_000_001, RoundingMode::Up),
+        Err(TryFromNumberError::Overflow)
+    );
+}
+
+#[test]
+fn test_from_google_decimal_overflow() {
+    assert_eq!(
+        Dec::from_google_decimal("6.5536e1", RoundingMode::Down),
+        Err(ParseNumberError::Overflow)
+    );
+    assert_eq!(
+        Dec::from_google_decimal("1e99999999999999", RoundingMode::Down),
+        Err(ParseNumberError::Overflow)
+    );
+}
+
+#[test]
+fn test_packed_bcd() {
+    assert_eq!(Dec::from_packed_bcd(&[0x65, 0x53, 0x5d], 3), Ok(Dec::min()));
+    assert_eq!(Dec::from_packed_bcd(&[0x01, 0x0c], 0), Ok(Dec::from(10)));
+    assert_eq!(
+        Dec::from_packed_bcd(&[0x65, 0x53, 0x6c], 3),
+        Err(PackedBcdError::Overflow)
+    );
+    for v in &[Dec::max(), Dec::min(), Dec::ulp()] {
+        let bytes = v.to_packed_bcd(9, 4).unwrap();
+        assert_eq!(Dec::from_packed_bcd(&bytes, 4), Ok(*v));
+    }
+}
@@ -97,3 +97,498 @@ fn test_constants() {
     assert_eq!(dec::consts::E.to_string(), "2.7182818285");
     assert_eq!(dec::consts::PI.to_string(), "3.1415926536");
 }
+
+#[test]
+fn test_to_decimal64() {
+    // 15000000000 * 10^-10
+    let v = Decimal::from_str("1.5").unwrap();
+    assert_eq!(
+        v.to_decimal64_bid(RoundingMode::Down),
+        Ok(0x3080_0003_7e11_d600)
+    );
+    assert_eq!(
+        (-v).to_decimal64_bid(RoundingMode::Down),
+        Ok(0xb080_0003_7e11_d600)
+    );
+    assert_eq!(
+        v.to_decimal128_bid(RoundingMode::Down),
+        Ok(0x302c_0000_0000_0000_0000_0003_7e11_d600)
+    );
+    assert_eq!(
+        Decimal::zero().to_decimal64_bid(RoundingMode::Down),
+        Ok(0x3080_0000_0000_0000)
+    );
+}
+
+#[test]
+fn test_from_decimal64() {
+    assert_eq!(
+        Decimal::from_decimal64_bid(0x31c0_0000_0000_0001, RoundingMode::Down),
+        Ok(Decimal::one())
+    );
+    assert_eq!(
+        Decimal::from_decimal64_dpd(0x2238_0000_0000_0001, RoundingMode::Down),
+        Ok(Decimal::one())
+    );
+    // 150000000 * 10^-8
+    assert_eq!(
+        Decimal::from_decimal64_bid(0x30c0_0000_08f0_d180, RoundingMode::Down),
+        Ok(Decimal::from_str("1.5").unwrap())
+    );
+    // -750 * 10^-2
+    assert_eq!(
+        Decimal::from_decimal64_dpd(0xa230_0000_0000_03d0, RoundingMode::Down),
+        Ok(Decimal::from_str("-7.5").unwrap())
+    );
+    assert_eq!(
+        Decimal::from_decimal64_bid(0x31c0_0000_0000_0000, RoundingMode::Down),
+        Ok(Decimal::zero())
+    );
+    assert!(
+        Decimal::from_decimal64_bid(0xb1c0_0000_0000_0000, RoundingMode::Down)
+            .unwrap()
+            .is_sign_positive()
+    );
+
+    // 123456789012 * 10^-11
+    let bits = 0x3060_001c_be99_1a14;
+    assert_eq!(
+        Decimal::from_decimal64_bid(bits, RoundingMode::Down),
+        Ok(Decimal::from_str("1.2345678901").unwrap())
+    );
+    assert_eq!(
+        Decimal::from_decimal64_bid(bits, RoundingMode::Up),
+        Ok(Decimal::from_str("1.2345678902").unwrap())
+    );
+    assert_eq!(
+        Decimal::from_decimal64_bid(bits | 1 << 63, RoundingMode::Floor),
+        Ok(Decimal::from_str("-1.2345678902").unwrap())
+    );
+
+    // 25 * 10^-11
+    let tie = 0x3060_0000_0000_0019;
+    assert_eq!(
+        Decimal::from_decimal64_bid(tie, RoundingMode::HalfEven),
+        Ok(Decimal::from_str("0.0000000002").unwrap())
+    );
+    assert_eq!(
+        Decimal::from_decimal64_bid(tie, RoundingMode::HalfUp),
+        Ok(Decimal::from_str("0.0000000003").unwrap())
+    );
+    assert_eq!(
+        Decimal::from_decimal64_bid(tie, RoundingMode::HalfDown),
+        Ok(Decimal::from_str("0.0000000002").unwrap())
+    );
+
+    // The smallest positive value
+    assert_eq!(
+        Decimal::from_decimal64_bid(1, RoundingMode::Up),
+        Ok(Decimal::ulp())
+    );
+    assert_eq!(
+        Decimal::from_decimal64_bid(1, RoundingMode::HalfUp),
+        Ok(Decimal::zero())
+    );
+}
+
+#[test]
+fn test_from_decimal128() {
+    assert_eq!(
+        Decimal::from_decimal128_bid(
+            0x3030_0000_0000_0000_0000_0000_08f0_d180,
+            RoundingMode::Down
+        ),
+        Ok(Decimal::from_str("1.5").unwrap())
+    );
+    assert_eq!(
+        Decimal::from_decimal128_dpd(
+            0x2208_0000_0000_0000_0000_0000_0000_0001,
+            RoundingMode::Down
+        ),
+        Ok(Decimal::one())
+    );
+    assert_eq!(
+        Decimal::from_decimal128_dpd(
+            0x77ff_cff3_fcff_3fcf_f3fc_ff3f_cff3_fcff,
+            RoundingMode::Down
+        ),
+        Err(TryFromNumberError::Overflow)
+    );
+}
+
+#[test]
+fn test_decimal_special_values() {
+    assert_eq!(
+        Decimal::nan().to_decimal64_bid(RoundingMode::Down),
+        Ok(0x7c00_0000_0000_0000)
+    );
+    assert_eq!(
+        Decimal::infinity().to_decimal64_dpd(RoundingMode::Down),
+        Ok(0x7800_0000_0000_0000)
+    );
+    assert_eq!(
+        Decimal::neg_infinity().to_decimal128_bid(RoundingMode::Down),
+        Ok(0xf800_0000_0000_0000_0000_0000_0000_0000)
+    );
+    assert!(
+        Decimal::from_decimal64_bid(0x7c00_0000_0000_0000, RoundingMode::Down)
+            .unwrap()
+            .is_nan()
+    );
+    // Signaling NaN
+    assert!(
+        Decimal::from_decimal64_dpd(0xfe00_0000_0000_0001, RoundingMode::Down)
+            .unwrap()
+            .is_nan()
+    );
+    assert_eq!(
+        Decimal::from_decimal128_dpd(
+            0xf800_0000_0000_0000_0000_0000_0000_0000,
+            RoundingMode::Down
+        ),
+        Ok(Decimal::neg_infinity())
+    );
+}
+
+#[test]
+fn test_decimal_round_trip() {
+    let values = [
+        Decimal::zero(),
+        Decimal::ulp(),
+        Decimal::from_str("-123456.78901234").unwrap(),
+        Decimal::max(),
+        Decimal::min(),
+    ];
+    for v in &values[..3] {
+        let bits = v.to_decimal64_bid(RoundingMode::Down).unwrap();
+        assert_eq!(
+            Decimal::from_decimal64_bid(bits, RoundingMode::Down),
+            Ok(*v)
+        );
+        let bits = v.to_decimal64_dpd(RoundingMode::Down).unwrap();
+        assert_eq!(
+            Decimal::from_decimal64_dpd(bits, RoundingMode::Down),
+            Ok(*v)
+        );
+    }
+    // Decimal128 coefficients have enough digits for any value of the type
+    for v in &values {
+        let bits = v.to_decimal128_bid(RoundingMode::Down).unwrap();
+        assert_eq!(
+            Decimal::from_decimal128_bid(bits, RoundingMode::Down),
+            Ok(*v)
+        );
+        let bits = v.to_decimal128_dpd(RoundingMode::Down).unwrap();
+        assert_eq!(
+            Decimal::from_decimal128_dpd(bits, RoundingMode::Down),
+            Ok(*v)
+        );
+    }
+}
+
+// Golden vectors were produced by `numeric_send()` of PostgreSQL
+#[test]
+fn test_to_pg_numeric() {
+    assert_eq!(
+        Decimal::from_str("1.5").unwrap().to_pg_numeric(),
+        [0, 2, 0, 0, 0, 0, 0, 10, 0, 1, 0x13, 0x88]
+    );
+    assert_eq!(
+        Decimal::from_str("-1.5").unwrap().to_pg_numeric(),
+        [0, 2, 0, 0, 0x40, 0, 0, 10, 0, 1, 0x13, 0x88]
+    );
+    assert_eq!(
+        Decimal::from_str("12345.678").unwrap().to_pg_numeric(),
+        [0, 3, 0, 1, 0, 0, 0, 10, 0, 1, 0x09, 0x29, 0x1a, 0x7c]
+    );
+    assert_eq!(
+        Decimal::from_str("0.00000001").unwrap().to_pg_numeric(),
+        [0, 1, 0xff, 0xfe, 0, 0, 0, 10, 0, 1]
+    );
+    assert_eq!(
+        Decimal::from_str("100000000").unwrap().to_pg_numeric(),
+        [0, 1, 0, 2, 0, 0, 0, 10, 0, 1]
+    );
+    assert_eq!(Decimal::zero().to_pg_numeric(), [0, 0, 0, 0, 0, 0, 0, 10]);
+}
+
+#[test]
+fn test_pg_numeric_special_values() {
+    assert_eq!(Decimal::nan().to_pg_numeric(), [0, 0, 0, 0, 0xc0, 0, 0, 0]);
+    assert_eq!(
+        Decimal::infinity().to_pg_numeric(),
+        [0, 0, 0, 0, 0xd0, 0, 0, 0]
+    );
+    assert_eq!(
+        Decimal::neg_infinity().to_pg_numeric(),
+        [0, 0, 0, 0, 0xf0, 0, 0, 0]
+    );
+    assert!(
+        Decimal::from_pg_numeric(&[0, 0, 0, 0, 0xc0, 0, 0, 0], RoundingMode::Down)
+            .unwrap()
+            .is_nan()
+    );
+    assert_eq!(
+        Decimal::from_pg_numeric(&[0, 0, 0, 0, 0xd0, 0, 0, 0], RoundingMode::Down),
+        Ok(Decimal::infinity())
+    );
+    assert_eq!(
+        Decimal::from_pg_numeric(&[0, 0, 0, 0, 0xf0, 0, 0, 0], RoundingMode::Down),
+        Ok(Decimal::neg_infinity())
+    );
+}
+
+#[test]
+fn test_from_pg_numeric() {
+    assert_eq!(
+        Decimal::from_pg_numeric(
+            &[0, 3, 0, 1, 0, 0, 0, 3, 0, 1, 0x09, 0x29, 0x1a, 0x7c],
+            RoundingMode::Down
+        ),
+        Ok(Decimal::from_str("12345.678").unwrap())
+    );
+    assert_eq!(
+        Decimal::from_pg_numeric(
+            &[0, 2, 0, 0, 0x40, 0, 0, 1, 0, 1, 0x13, 0x88],
+            RoundingMode::Down
+        ),
+        Ok(Decimal::from_str("-1.5").unwrap())
+    );
+    assert_eq!(
+        Decimal::from_pg_numeric(&[0, 0, 0, 0, 0, 0, 0, 2], RoundingMode::Up),
+        Ok(Decimal::zero())
+    );
+
+    // 1.23456789012
+    let bytes = [
+        0, 4, 0, 0, 0, 0, 0, 11, 0, 1, 0x09, 0x29, 0x1a, 0x85, 0, 120,
+    ];
+    assert_eq!(
+        Decimal::from_pg_numeric(&bytes, RoundingMode::Down),
+        Ok(Decimal::from_str("1.2345678901").unwrap())
+    );
+    assert_eq!(
+        Decimal::from_pg_numeric(&bytes, RoundingMode::Up),
+        Ok(Decimal::from_str("1.2345678902").unwrap())
+    );
+    // -0.00000000025
+    let tie = [0, 1, 0xff, 0xfd, 0x40, 0, 0, 11, 0, 250];
+    assert_eq!(
+        Decimal::from_pg_numeric(&tie, RoundingMode::HalfEven),
+        Ok(Decimal::from_str("-0.0000000002").unwrap())
+    );
+    assert_eq!(
+        Decimal::from_pg_numeric(&tie, RoundingMode::HalfUp),
+        Ok(Decimal::from_str("-0.0000000003").unwrap())
+    );
+    assert_eq!(
+        Decimal::from_pg_numeric(&tie, RoundingMode::Ceiling),
+        Ok(Decimal::from_str("-0.0000000002").unwrap())
+    );
+    // 10^-400 is below the ulp
+    let below = [0, 1, 0xff, 0x9c, 0, 0, 0x01, 0x90, 0, 1];
+    assert_eq!(
+        Decimal::from_pg_numeric(&below, RoundingMode::Down),
+        Ok(Decimal::zero())
+    );
+    assert_eq!(
+        Decimal::from_pg_numeric(&below, RoundingMode::Up),
+        Ok(Decimal::ulp())
+    );
+}
+
+#[test]
+fn test_from_pg_numeric_errors() {
+    assert_eq!(
+        Decimal::from_pg_numeric(&[0, 2, 0, 0, 0, 0, 0, 1, 0, 1], RoundingMode::Down),
+        Err(FromBytesError::BufferTooShort)
+    );
+    assert_eq!(
+        Decimal::from_pg_numeric(&[0, 0, 0, 0, 0x20, 0, 0, 1], RoundingMode::Down),
+        Err(FromBytesError::InvalidFormat)
+    );
+    assert_eq!(
+        Decimal::from_pg_numeric(&[0, 1, 0, 0, 0, 0, 0, 1, 0x27, 0x10], RoundingMode::Down),
+        Err(FromBytesError::InvalidFormat)
+    );
+    // 10^12
+    assert_eq!(
+        Decimal::from_pg_numeric(&[0, 1, 0, 3, 0, 0, 0, 0, 0, 1], RoundingMode::Down),
+        Err(FromBytesError::Overflow)
+    );
+}
+
+#[test]
+fn test_pg_numeric_round_trip() {
+    for v in &[
+        Decimal::ulp(),
+        Decimal::from_str("-0.5").unwrap(),
+        Decimal::from_str("9999.9999").unwrap(),
+        Decimal::from_str("-123456.78901234").unwrap(),
+        Decimal::max(),
+        Decimal::min(),
+    ] {
+        assert_eq!(
+            Decimal::from_pg_numeric(&v.to_pg_numeric(), RoundingMode::Down),
+            Ok(*v)
+        );
+    }
+}
+
+#[test]
+fn test_units_nanos() {
+    let v = Decimal::from_str("0.00000001").unwrap();
+    assert_eq!(v.to_units_nanos(RoundingMode::Down), Ok((0, 10)));
+    assert_eq!(
+        Decimal::ulp().to_units_nanos(RoundingMode::Down),
+        Ok((0, 0))
+    );
+    assert_eq!(Decimal::ulp().to_units_nanos(RoundingMode::Up), Ok((0, 1)));
+    assert_eq!(
+        Decimal::max().to_units_nanos(RoundingMode::Down),
+        Ok((1844674407, 370955161))
+    );
+    assert_eq!(
+        Decimal::min().to_units_nanos(RoundingMode::HalfUp),
+        Ok((-1844674407, -370955162))
+    );
+    for v in &[
+        Decimal::from_str("-0.000000001").unwrap(),
+        Decimal::from_str("-123456.789").unwrap(),
+        Decimal::from_str("1844674407.370955161").unwrap(),
+    ] {
+        let (units, nanos) = v.to_units_nanos(RoundingMode::Down).unwrap();
+        assert_eq!(
+            Decimal::from_units_nanos(units, nanos, RoundingMode::Down),
+            Ok(*v)
+        );
+    }
+}
+
+#[test]
+fn test_from_google_decimal() {
+    assert_eq!(
+        Decimal::from_google_decimal("2.5e-10", RoundingMode::HalfEven),
+        Ok(Decimal::from_str("0.0000000002").unwrap())
+    );
+    assert_eq!(
+        Decimal::from_google_decimal("2.5e-10", RoundingMode::HalfUp),
+        Ok(Decimal::from_str("0.0000000003").unwrap())
+    );
+    assert_eq!(
+        Decimal::from_google_decimal("1e-1000000", RoundingMode::Up),
+        Ok(Decimal::ulp())
+    );
+    assert_eq!(
+        Decimal::from_google_decimal("1e-99999999999999", RoundingMode::Down),
+        Ok(Decimal::zero())
+    );
+}
+
+#[test]
+fn test_to_packed_bcd() {
+    // PIC S9(5)V99 COMP-3
+    let v = Decimal::from_str("123.45").unwrap();
+    assert_eq!(v.to_packed_bcd(7, 2), Ok(vec![0x00, 0x12, 0x34, 0x5c]));
+    assert_eq!((-v).to_packed_bcd(7, 2), Ok(vec![0x00, 0x12, 0x34, 0x5d]));
+    // PIC S9(4)V99 COMP-3
+    assert_eq!(
+        Decimal::from_str("-1234.5").unwrap().to_packed_bcd(6, 2),
+        Ok(vec![0x01, 0x23, 0x45, 0x0d])
+    );
+    assert_eq!(Decimal::zero().to_packed_bcd(3, 0), Ok(vec![0x00, 0x0c]));
+    assert_eq!(Decimal::from(7).to_packed_bcd(1, 0), Ok(vec![0x7c]));
+    assert_eq!(
+        Decimal::from_str("0.0001").unwrap().to_packed_bcd(8, 8),
+        Ok(vec![0x00, 0x00, 0x10, 0x00, 0x0c])
+    );
+    assert_eq!(
+        Decimal::max().to_packed_bcd(20, 10),
+        Ok(vec![
+            0x01, 0x84, 0x46, 0x74, 0x40, 0x73, 0x70, 0x95, 0x51, 0x61, 0x5c
+        ])
+    );
+}
+
+#[test]
+fn test_to_packed_bcd_errors() {
+    let v = Decimal::from_str("123.45").unwrap();
+    assert_eq!(v.to_packed_bcd(4, 1), Err(TryFromNumberError::Fraction));
+    assert_eq!(v.to_packed_bcd(4, 2), Err(TryFromNumberError::Overflow));
+    assert_eq!(
+        Decimal::max().to_packed_bcd(19, 10),
+        Err(TryFromNumberError::Overflow)
+    );
+    assert_eq!(
+        Decimal::nan().to_packed_bcd(5, 2),
+        Err(TryFromNumberError::Special)
+    );
+    assert_eq!(
+        Decimal::neg_infinity().to_packed_bcd(5, 2),
+        Err(TryFromNumberError::Special)
+    );
+}
+
+#[test]
+#[should_panic(expected = "scale must not exceed the number of digits")]
+fn test_to_packed_bcd_invalid_scale() {
+    let _ = Decimal::one().to_packed_bcd(2, 3);
+}
+
+#[test]
+fn test_from_packed_bcd() {
+    assert_eq!(
+        Decimal::from_packed_bcd(&[0x00, 0x12, 0x34, 0x5c], 2),
+        Ok(Decimal::from_str("123.45").unwrap())
+    );
+    assert_eq!(
+        Decimal::from_packed_bcd(&[0x00, 0x12, 0x34, 0x5d], 2),
+        Ok(Decimal::from_str("-123.45").unwrap())
+    );
+    assert_eq!(
+        Decimal::from_packed_bcd(&[0x12, 0x34, 0x5f], 0),
+        Ok(Decimal::from(12345))
+    );
+    assert_eq!(
+        Decimal::from_packed_bcd(&[0x12, 0x30, 0x0c], 6),
+        Ok(Decimal::from_str("0.0123").unwrap())
+    );
+    assert_eq!(
+        Decimal::from_packed_bcd(&[0x00, 0x0d], 2),
+        Ok(Decimal::zero())
+    );
+    assert!(Decimal::from_packed_bcd(&[0x00, 0x0d], 2)
+        .unwrap()
+        .is_sign_positive());
+}
+
+#[test]
+fn test_from_packed_bcd_errors() {
+    assert_eq!(Decimal::from_packed_bcd(&[], 2), Err(PackedBcdError::Empty));
+    assert_eq!(
+        Decimal::from_packed_bcd(&[0x12, 0x3b], 2),
+        Err(PackedBcdError::InvalidSign(0x0b))
+    );
+    assert_eq!(
+        Decimal::from_packed_bcd(&[0x1f, 0x3c], 2),
+        Err(PackedBcdError::InvalidDigit(1))
+    );
+    assert_eq!(
+        Decimal::from_packed_bcd(&[0x12, 0x34, 0x5c], 12),
+        Err(PackedBcdError::Fraction)
+    );
+}
+
+#[test]
+fn test_packed_bcd_round_trip() {
+    for v in &[
+        Decimal::ulp(),
+        Decimal::from_str("-123456.7891").unwrap(),
+        Decimal::max(),
+        Decimal::min(),
+    ] {
+        let bytes = v.to_packed_bcd(21, 10).unwrap();
+        assert_eq!(bytes.len(), 11);
+        assert_eq!(Decimal::from_packed_bcd(&bytes, 10), Ok(*v));
+    }
+}
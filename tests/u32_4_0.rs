@@ -36,3 +36,53 @@ fn test_constants() {
     assert_eq!(*int::consts::E, Int::from(3));
     assert_eq!(*int::consts::PI, Int::from(3));
 }
+
+#[test]
+fn test_to_decimal() {
+    // 1 * 10^0
+    assert_eq!(
+        Int::one().to_decimal64_bid(RoundingMode::Down),
+        Ok(0x31c0_0000_0000_0001)
+    );
+    assert_eq!(
+        Int::one().to_decimal64_dpd(RoundingMode::Down),
+        Ok(0x2238_0000_0000_0001)
+    );
+    assert_eq!(
+        Int::one().to_decimal128_bid(RoundingMode::Down),
+        Ok(0x3040_0000_0000_0000_0000_0000_0000_0001)
+    );
+    assert_eq!(
+        Int::one().to_decimal128_dpd(RoundingMode::Down),
+        Ok(0x2208_0000_0000_0000_0000_0000_0000_0001)
+    );
+}
+
+#[test]
+fn test_pg_numeric() {
+    assert_eq!(Int::one().to_pg_numeric(), [0, 1, 0, 0, 0, 0, 0, 0, 0, 1]);
+}
+
+#[test]
+fn test_units_nanos() {
+    let max = Int::from(i64::MAX);
+    assert_eq!(max.to_units_nanos(RoundingMode::Down), Ok((i64::MAX, 0)));
+    assert_eq!(
+        (max + Int::one()).to_units_nanos(RoundingMode::Down),
+        Err(TryFromNumberError::Overflow)
+    );
+    let min = Int::from(i64::MIN);
+    assert_eq!(min.to_units_nanos(RoundingMode::Down), Ok((i64::MIN, 0)));
+    assert_eq!(
+        (min - Int::one()).to_units_nanos(RoundingMode::Down),
+        Err(TryFromNumberError::Overflow)
+    );
+    assert_eq!(
+        Int::max().to_units_nanos(RoundingMode::Down),
+        Err(TryFromNumberError::Overflow)
+    );
+    assert_eq!(
+        Int::from_units_nanos(i64::MIN, -999_999_999, RoundingMode::Floor),
+        Ok(min - Int::one())
+    );
+}
@@ -184,6 +184,7 @@ mod basic {
 #[cfg(test)]
 mod cmp {
     use super::decimal::*;
+    use std::str::FromStr;
 
     use std::cmp::Ordering;
 
@@ -278,6 +279,130 @@ mod cmp {
         );
     }
 
+    #[test]
+    fn test_total_cmp() {
+        let ordered = [
+            Decimal::neg_infinity(),
+            Decimal::min(),
+            Decimal::from(-1),
+            -Decimal::ulp(),
+            Decimal::zero(),
+            Decimal::ulp(),
+            Decimal::one(),
+            Decimal::max(),
+            Decimal::infinity(),
+            Decimal::nan(),
+        ];
+        for (i, a) in ordered.iter().enumerate() {
+            for (j, b) in ordered.iter().enumerate() {
+                assert_eq!(a.total_cmp(b), i.cmp(&j), "{} <=> {}", a, b);
+            }
+        }
+        assert_eq!(
+            Decimal::zero().total_cmp(&-Decimal::zero()),
+            Ordering::Equal
+        );
+        assert_eq!(
+            (-Decimal::nan()).total_cmp(&Decimal::nan()),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn test_total() {
+        use std::collections::BTreeMap;
+
+        let mut vals = vec![
+            Total(Decimal::nan()),
+            Total(Decimal::from(3)),
+            Total(Decimal::neg_infinity()),
+            Total(Decimal::nan()),
+            Total(Decimal::from(-3)),
+            Total(Decimal::from(3)),
+        ];
+        vals.sort();
+        vals.dedup();
+        let expected = [
+            Decimal::neg_infinity(),
+            Decimal::from(-3),
+            Decimal::from(3),
+            Decimal::nan(),
+        ];
+        assert_eq!(vals.len(), expected.len());
+        for (v, e) in vals.iter().zip(expected.iter()) {
+            assert_eq!(v.cmp(&Total(*e)), Ordering::Equal);
+        }
+        assert_eq!(Total(Decimal::nan()), Total(Decimal::nan()));
+        assert_ne!(Total(Decimal::nan()), Total(Decimal::infinity()));
+        assert!(Total(Decimal::max()) < Total(Decimal::infinity()));
+        assert_eq!(Total::from(Decimal::one()).0, Decimal::one());
+
+        let mut levels = BTreeMap::new();
+        *levels.entry(Total(Decimal::with_scale(15, 1))).or_insert(0) += 1;
+        *levels.entry(Total(Decimal::nan())).or_insert(0) += 1;
+        *levels
+            .entry(Total(Decimal::with_scale(150, 2)))
+            .or_insert(0) += 1;
+        *levels.entry(Total(Decimal::nan())).or_insert(0) += 1;
+        assert_eq!(levels.len(), 2);
+        assert_eq!(levels[&Total(Decimal::with_scale(15, 1))], 2);
+        assert_eq!(levels[&Total(Decimal::nan())], 2);
+    }
+
+    #[test]
+    fn test_hash() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::collections::HashMap;
+        use std::hash::{Hash, Hasher};
+
+        fn hash(v: Decimal) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            v.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        assert_eq!(hash(Decimal::zero()), hash(-Decimal::zero()));
+        assert_eq!(hash(Decimal::zero()), hash(Decimal::one() - Decimal::one()));
+        assert_eq!(hash(Decimal::zero()), hash(Decimal::from(-1) + 1));
+        assert_eq!(
+            hash(Decimal::zero()),
+            hash(Decimal::ulp() * -Decimal::ulp())
+        );
+        assert_eq!(
+            hash(Decimal::with_scale(15, 1)),
+            hash(Decimal::with_scale(150, 2))
+        );
+        assert_eq!(hash(Decimal::infinity()), hash(Decimal::one() / 0));
+        assert_ne!(hash(Decimal::one()), hash(-Decimal::one()));
+        assert_ne!(hash(Decimal::infinity()), hash(Decimal::neg_infinity()));
+
+        // Map keys need `Eq`, which is provided by the `Total` wrapper
+        let mut levels: HashMap<Total<Decimal>, u32> = HashMap::new();
+        for v in ["1.5", "-1.5", "1.50", "2", "-1.500"].iter() {
+            *levels
+                .entry(Total(Decimal::from_str(v).unwrap()))
+                .or_insert(0) += 1;
+        }
+        assert_eq!(levels.len(), 3);
+        assert_eq!(levels[&Total(Decimal::with_scale(15, 1))], 2);
+        assert_eq!(levels[&Total(Decimal::with_scale(-15, 1))], 2);
+        assert_eq!(levels[&Total(Decimal::from(2))], 1);
+    }
+
+    #[test]
+    fn test_total_hash() {
+        use std::collections::HashSet;
+
+        let mut set: HashSet<Total<Decimal>> = HashSet::new();
+        set.insert(Total(Decimal::nan()));
+        set.insert(Total(Decimal::zero() / 0));
+        set.insert(Total(Decimal::zero()));
+        set.insert(Total(-Decimal::zero()));
+        set.insert(Total(Decimal::infinity()));
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(&Total(Decimal::nan())));
+    }
+
     fn assert_eq(a: Decimal, b: Decimal, expected: bool) {
         if expected {
             assert_eq!(a, b);
@@ -462,6 +587,86 @@ mod ops {
         assert_eq!(n, Decimal::with_scale(625, 2));
     }
 
+    #[test]
+    fn test_sum() {
+        let vals = [
+            Decimal::with_scale(15, 1),
+            Decimal::from(-4),
+            Decimal::ulp(),
+            Decimal::with_scale(25, 1),
+        ];
+        assert_eq!(vals.iter().sum::<Decimal>(), Decimal::ulp());
+        assert_eq!(vals.iter().cloned().sum::<Decimal>(), Decimal::ulp());
+        assert_eq!(
+            vals[..2].iter().sum::<Decimal>(),
+            Decimal::with_scale(-25, 1)
+        );
+        assert_eq!(
+            std::iter::empty::<Decimal>().sum::<Decimal>(),
+            Decimal::zero()
+        );
+        assert!([Decimal::one(), -Decimal::one()]
+            .iter()
+            .sum::<Decimal>()
+            .is_sign_positive());
+    }
+
+    #[test]
+    fn test_sum_overflow() {
+        // Intermediate sums may exceed the type range
+        let vals = [Decimal::max(), Decimal::max(), Decimal::min()];
+        assert_eq!(vals.iter().sum::<Decimal>(), Decimal::max());
+        let vals = [Decimal::min(), Decimal::min(), Decimal::max()];
+        assert_eq!(vals.iter().sum::<Decimal>(), Decimal::min());
+        let vals = [Decimal::max(), Decimal::ulp()];
+        assert_eq!(vals.iter().sum::<Decimal>(), Decimal::infinity());
+        let vals = [Decimal::min(), -Decimal::ulp()];
+        assert_eq!(vals.iter().sum::<Decimal>(), Decimal::neg_infinity());
+        assert_eq!(
+            (0..1000).map(|_| Decimal::max()).sum::<Decimal>(),
+            Decimal::infinity()
+        );
+    }
+
+    #[test]
+    fn test_sum_special() {
+        let one = Decimal::one();
+        let inf = Decimal::infinity();
+        let neg_inf = Decimal::neg_infinity();
+        assert!([one, Decimal::nan(), inf].iter().sum::<Decimal>().is_nan());
+        assert!([inf, one, neg_inf].iter().sum::<Decimal>().is_nan());
+        assert_eq!([inf, Decimal::min(), inf].iter().sum::<Decimal>(), inf);
+        assert_eq!([one, neg_inf].iter().sum::<Decimal>(), neg_inf);
+    }
+
+    #[test]
+    fn test_product() {
+        let vals = [
+            Decimal::with_scale(15, 1),
+            Decimal::from(-4),
+            Decimal::with_scale(25, 1),
+        ];
+        assert_eq!(vals.iter().product::<Decimal>(), Decimal::from(-15));
+        assert_eq!(
+            vals.iter().cloned().product::<Decimal>(),
+            Decimal::from(-15)
+        );
+        assert_eq!(
+            std::iter::empty::<Decimal>().product::<Decimal>(),
+            Decimal::one()
+        );
+        assert!([Decimal::infinity(), Decimal::zero()]
+            .iter()
+            .product::<Decimal>()
+            .is_nan());
+        assert_eq!(
+            [Decimal::max(), Decimal::from(2)]
+                .iter()
+                .product::<Decimal>(),
+            Decimal::infinity()
+        );
+    }
+
     #[test]
     fn test_sub_zero() {
         let vals = [
@@ -1135,6 +1340,8 @@ mod ops {
 mod prim {
     use super::decimal::*;
     use super::test_str;
+    use std::convert::TryFrom;
+    use std::str::FromStr;
 
     #[test]
     fn test_from_u8() {
@@ -1306,6 +1513,125 @@ mod prim {
         );
     }
 
+    #[test]
+    fn test_from_u128() {
+        test_str(Decimal::from(0u128), "0");
+        test_str(Decimal::from(42u128), "42");
+        test_str(Decimal::from(u64::MAX as u128 + 1), "18446744073709551616");
+        test_str(
+            Decimal::from(146150163733090291820368u128),
+            "146150163733090291820368",
+        );
+        assert_eq!(
+            Decimal::from(146150163733090291820369u128),
+            Decimal::infinity()
+        );
+        assert_eq!(Decimal::from(u128::MAX), Decimal::infinity());
+    }
+
+    #[test]
+    fn test_from_u128_with_scale() {
+        test_str(Decimal::with_scale(0u128, 4), "0");
+        test_str(Decimal::with_scale(42u128, 1), "4.2");
+        test_str(
+            Decimal::with_scale(u128::MAX, 25),
+            "34028236692093.8463463374607431768211455",
+        );
+        test_str(
+            Decimal::with_scale(146150163733090291820368u128, 0),
+            "146150163733090291820368",
+        );
+    }
+
+    #[test]
+    fn test_from_i128() {
+        test_str(Decimal::from(0i128), "0");
+        test_str(Decimal::from(-1i128), "-1");
+        test_str(Decimal::from(i64::MIN as i128 - 1), "-9223372036854775809");
+        test_str(
+            Decimal::from(-146150163733090291820368i128),
+            "-146150163733090291820368",
+        );
+        assert_eq!(Decimal::from(i128::MAX), Decimal::infinity());
+        assert_eq!(Decimal::from(i128::MIN), Decimal::neg_infinity());
+    }
+
+    #[test]
+    fn test_from_i128_with_scale() {
+        test_str(Decimal::with_scale(0i128, 4), "0");
+        test_str(Decimal::with_scale(-42i128, 1), "-4.2");
+        test_str(
+            Decimal::with_scale(i128::MAX, 25),
+            "17014118346046.9231731687303715884105727",
+        );
+        test_str(
+            Decimal::with_scale(i128::MIN, 25),
+            "-17014118346046.9231731687303715884105728",
+        );
+    }
+
+    #[test]
+    fn test_ops_with_128_bit_primitives() {
+        assert_eq!(Decimal::one() + 5u128, Decimal::from(6));
+        assert_eq!(Decimal::one() - 5i128, Decimal::from(-4));
+        assert_eq!(
+            7i128 * Decimal::with_scale(15, 1),
+            Decimal::with_scale(105, 1)
+        );
+        assert_eq!(Decimal::from(10) / 4u128, Decimal::with_scale(25, 1));
+        assert_eq!(10u128 % Decimal::from(4), Decimal::from(2));
+        let mut n = Decimal::one();
+        n += 2u128;
+        n *= -3i128;
+        assert_eq!(n, Decimal::from(-9));
+    }
+
+    #[test]
+    fn test_from_usize() {
+        test_str(Decimal::from(0usize), "0");
+        test_str(Decimal::from(vec![1, 2, 3].len()), "3");
+        test_str(Decimal::from(usize::MAX), &usize::MAX.to_string());
+        test_str(Decimal::with_scale(42usize, 1), "4.2");
+        test_str(Decimal::with_scale(10000060008usize, 6), "10000.060008");
+    }
+
+    #[test]
+    fn test_from_isize() {
+        test_str(Decimal::from(0isize), "0");
+        test_str(Decimal::from(-42isize), "-42");
+        test_str(Decimal::from(isize::MIN), &isize::MIN.to_string());
+        test_str(Decimal::from(isize::MAX), &isize::MAX.to_string());
+        test_str(Decimal::with_scale(-42isize, 1), "-4.2");
+        test_str(Decimal::with_scale(-1isize, 3), "-0.001");
+    }
+
+    #[test]
+    fn test_ops_with_pointer_sized_primitives() {
+        let price = Decimal::with_scale(125, 2);
+        let index: usize = 4;
+        assert_eq!(price * index, Decimal::from(5));
+        assert_eq!(index + price, Decimal::with_scale(525, 2));
+        assert_eq!(price - 2isize, Decimal::with_scale(-75, 2));
+        assert_eq!(5isize / Decimal::from(2), Decimal::with_scale(25, 1));
+        assert_eq!(Decimal::from(7) % 4usize, Decimal::from(3));
+        let mut n = price;
+        n += index;
+        n /= -2isize;
+        assert_eq!(n, Decimal::with_scale(-2625, 3));
+    }
+
+    #[test]
+    fn test_to_pointer_sized_primitives() {
+        assert_eq!(usize::try_from(Decimal::from(42)), Ok(42));
+        assert_eq!(
+            usize::try_from(Decimal::from(-1)),
+            Err(TryFromNumberError::Overflow)
+        );
+        assert_eq!(isize::try_from(Decimal::from(-42)), Ok(-42));
+        assert_eq!(Decimal::with_scale(-25, 1).to_isize_rounded(), Ok(-3));
+        assert_eq!(Decimal::with_scale(25, 1).to_usize_truncated(), Ok(2));
+    }
+
     #[test]
     fn test_from_f32() {
         use std::f32::{INFINITY, NAN, NEG_INFINITY};
@@ -1367,6 +1693,378 @@ mod prim {
         assert_eq!(Decimal::from(-1.0e+25_f64), Decimal::neg_infinity());
     }
 
+    #[test]
+    fn test_from_f64_exact() {
+        use fdec::RoundingMode::*;
+
+        assert!(Decimal::from_f64_exact(f64::NAN, Down).is_nan());
+        assert_eq!(
+            Decimal::from_f64_exact(f64::NEG_INFINITY, Up),
+            Decimal::neg_infinity()
+        );
+        assert_eq!(Decimal::from_f64_exact(-0.0, Floor), Decimal::zero());
+        test_str(Decimal::from_f64_exact(0.375, Up), "0.375");
+        test_str(Decimal::from_f64_exact(-1e20, Up), "-100000000000000000000");
+        test_str(
+            Decimal::from_f64_exact(0.1, Down),
+            "0.1000000000000000055511151",
+        );
+        test_str(
+            Decimal::from_f64_exact(0.1, HalfEven),
+            "0.1000000000000000055511151",
+        );
+        test_str(
+            Decimal::from_f64_exact(0.1, Up),
+            "0.1000000000000000055511152",
+        );
+        test_str(
+            Decimal::from_f64_exact(-0.1, Floor),
+            "-0.1000000000000000055511152",
+        );
+        test_str(
+            Decimal::from_f64_exact(-0.1, Ceiling),
+            "-0.1000000000000000055511151",
+        );
+
+        // 2^-26 and 3 * 2^-26 have 26 fraction digits, the last one is 5
+        let (even, odd) = (2f64.powi(-26), 3.0 * 2f64.powi(-26));
+        test_str(
+            Decimal::from_f64_exact(even, HalfEven),
+            "0.0000000149011611938476562",
+        );
+        test_str(
+            Decimal::from_f64_exact(even, HalfUp),
+            "0.0000000149011611938476563",
+        );
+        test_str(
+            Decimal::from_f64_exact(even, HalfDown),
+            "0.0000000149011611938476562",
+        );
+        test_str(
+            Decimal::from_f64_exact(odd, HalfEven),
+            "0.0000000447034835815429688",
+        );
+        test_str(
+            Decimal::from_f64_exact(odd, HalfDown),
+            "0.0000000447034835815429687",
+        );
+        test_str(
+            Decimal::from_f64_exact(-odd, HalfUp),
+            "-0.0000000447034835815429688",
+        );
+
+        // Values below the ulp
+        assert_eq!(Decimal::from_f64_exact(1e-30, HalfUp), Decimal::zero());
+        assert_eq!(Decimal::from_f64_exact(1e-30, Up), Decimal::ulp());
+        assert_eq!(Decimal::from_f64_exact(-1e-30, Floor), -Decimal::ulp());
+        assert_eq!(Decimal::from_f64_exact(-1e-30, Ceiling), Decimal::zero());
+        assert_eq!(
+            Decimal::from_f64_exact(f64::from_bits(1), Up),
+            Decimal::ulp()
+        );
+        assert_eq!(
+            Decimal::from_f64_exact(f64::MIN_POSITIVE, HalfEven),
+            Decimal::zero()
+        );
+
+        // With overflow
+        test_str(
+            Decimal::from_f64_exact(1e23, Down),
+            "99999999999999991611392",
+        );
+        assert_eq!(Decimal::from_f64_exact(2e23, Down), Decimal::infinity());
+        assert_eq!(
+            Decimal::from_f64_exact(-f64::MAX, Down),
+            Decimal::neg_infinity()
+        );
+    }
+
+    #[test]
+    fn test_from_f64_shortest() {
+        use fdec::RoundingMode::*;
+
+        assert!(Decimal::from_f64_shortest(f64::NAN, Down).is_nan());
+        assert_eq!(
+            Decimal::from_f64_shortest(f64::INFINITY, Up),
+            Decimal::infinity()
+        );
+        assert_eq!(Decimal::from_f64_shortest(0.0, Up), Decimal::zero());
+        test_str(Decimal::from_f64_shortest(0.1, Up), "0.1");
+        test_str(Decimal::from_f64_shortest(-0.02, Floor), "-0.02");
+        test_str(
+            Decimal::from_f64_shortest(0.1 + 0.2, Down),
+            "0.30000000000000004",
+        );
+        test_str(Decimal::from_f64_shortest(2_000_000.02, Down), "2000000.02");
+        test_str(
+            Decimal::from_f64_shortest(123_456_789.123_456_79, Down),
+            "123456789.12345679",
+        );
+        test_str(
+            Decimal::from_f64_shortest(1e23, Down),
+            "100000000000000000000000",
+        );
+
+        // Values with more fraction digits than the type scale
+        test_str(
+            Decimal::from_f64_shortest(1.25e-25, HalfEven),
+            "0.0000000000000000000000001",
+        );
+        test_str(
+            Decimal::from_f64_shortest(1.25e-25, Up),
+            "0.0000000000000000000000002",
+        );
+        test_str(
+            Decimal::from_f64_shortest(1.5e-25, HalfEven),
+            "0.0000000000000000000000002",
+        );
+        test_str(
+            Decimal::from_f64_shortest(-1.5e-25, HalfDown),
+            "-0.0000000000000000000000001",
+        );
+        assert_eq!(Decimal::from_f64_shortest(5e-26, HalfEven), Decimal::zero());
+        assert_eq!(Decimal::from_f64_shortest(5e-26, HalfUp), Decimal::ulp());
+        assert_eq!(Decimal::from_f64_shortest(1e-30, Up), Decimal::ulp());
+        assert_eq!(Decimal::from_f64_shortest(-1e-30, Floor), -Decimal::ulp());
+        assert_eq!(Decimal::from_f64_shortest(-1e-300, HalfUp), Decimal::zero());
+
+        // With overflow
+        assert_eq!(Decimal::from_f64_shortest(2e23, Down), Decimal::infinity());
+        assert_eq!(
+            Decimal::from_f64_shortest(-f64::MAX, Down),
+            Decimal::neg_infinity()
+        );
+    }
+
+    #[test]
+    fn test_from_f32_exact_and_shortest() {
+        use fdec::RoundingMode::*;
+
+        assert!(Decimal::from_f32_exact(f32::NAN, Down).is_nan());
+        assert!(Decimal::from_f32_shortest(f32::NAN, Down).is_nan());
+        test_str(
+            Decimal::from_f32_exact(0.1, HalfEven),
+            "0.1000000014901161193847656",
+        );
+        test_str(
+            Decimal::from_f32_exact(0.1, Up),
+            "0.1000000014901161193847657",
+        );
+        test_str(Decimal::from_f32_shortest(0.1, Up), "0.1");
+        test_str(Decimal::from_f32_shortest(-2_000_000.2, Down), "-2000000.3");
+        test_str(Decimal::from_f32_shortest(16777217.0, Down), "16777216");
+        assert_eq!(Decimal::from_f32_shortest(1e-40, Up), Decimal::ulp());
+        assert_eq!(Decimal::from_f32_exact(f32::MAX, Down), Decimal::infinity());
+    }
+
+    #[test]
+    fn test_to_f64() {
+        assert!(Decimal::nan().to_f64().is_nan());
+        assert_eq!(Decimal::infinity().to_f64(), f64::INFINITY);
+        assert_eq!(Decimal::neg_infinity().to_f64(), f64::NEG_INFINITY);
+        assert_eq!(Decimal::zero().to_f64(), 0.0);
+        assert_eq!(Decimal::one().to_f64(), 1.0);
+        assert_eq!(Decimal::from(-54321).to_f64(), -54321.0);
+        assert_eq!(Decimal::with_scale(1, 1).to_f64(), 0.1);
+        assert_eq!(Decimal::with_scale(-2, 2).to_f64(), -0.02);
+        assert_eq!(Decimal::ulp().to_f64(), 1e-25);
+        assert_eq!(f64::from(Decimal::with_scale(15, 1)), 1.5);
+        assert_eq!(
+            f64::from(Decimal::max()),
+            146150163733090291820368.4832716283019655932542975
+        );
+        for s in [
+            "0.3333333333333333333333333",
+            "-0.6666666666666666666666667",
+            "2.7182818284590452353602875",
+            "9007199254740993",
+            "9007199254740993.0000000000000000000000001",
+            "-9007199254740995",
+            "123456789.987654321",
+            "0.0000000000000000000000123",
+        ] {
+            let d = Decimal::from_str(s).unwrap();
+            assert_eq!(d.to_f64(), s.parse::<f64>().unwrap(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_to_f32() {
+        assert!(Decimal::nan().to_f32().is_nan());
+        assert_eq!(Decimal::infinity().to_f32(), f32::INFINITY);
+        assert_eq!(Decimal::neg_infinity().to_f32(), f32::NEG_INFINITY);
+        assert_eq!(Decimal::zero().to_f32(), 0.0);
+        assert_eq!(Decimal::from(-3).to_f32(), -3.0);
+        assert_eq!(Decimal::with_scale(1, 1).to_f32(), 0.1);
+        assert_eq!(f32::from(Decimal::ulp()), 1e-25);
+        for s in [
+            "16777217",
+            "16777217.0000000000000000000000001",
+            "-16777219",
+            "0.3333333333333333333333333",
+            "146150163733090291820368.4832716283019655932542975",
+        ] {
+            let d = Decimal::from_str(s).unwrap();
+            assert_eq!(d.to_f32(), s.parse::<f32>().unwrap(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_try_into_unsigned() {
+        assert_eq!(u8::try_from(Decimal::zero()), Ok(0));
+        assert_eq!(u8::try_from(Decimal::from(255)), Ok(255));
+        assert_eq!(u16::try_from(Decimal::from(65535)), Ok(65535));
+        assert_eq!(u32::try_from(Decimal::from(u32::MAX)), Ok(u32::MAX));
+        assert_eq!(u64::try_from(Decimal::from(u64::MAX)), Ok(u64::MAX));
+        assert_eq!(
+            u128::try_from(Decimal::from_str("146150163733090291820368").unwrap()),
+            Ok(146150163733090291820368)
+        );
+        assert_eq!(u8::try_from(-Decimal::zero()), Ok(0));
+
+        assert_eq!(
+            u8::try_from(Decimal::from(256)),
+            Err(TryFromNumberError::Overflow)
+        );
+        assert_eq!(
+            u64::try_from(Decimal::max().trunc()),
+            Err(TryFromNumberError::Overflow)
+        );
+        assert_eq!(
+            u32::try_from(Decimal::from(-1)),
+            Err(TryFromNumberError::Overflow)
+        );
+        assert_eq!(
+            u32::try_from(Decimal::with_scale(15, 1)),
+            Err(TryFromNumberError::Fraction)
+        );
+        assert_eq!(
+            u128::try_from(Decimal::ulp()),
+            Err(TryFromNumberError::Fraction)
+        );
+    }
+
+    #[test]
+    fn test_try_into_signed() {
+        assert_eq!(i8::try_from(Decimal::from(127)), Ok(127));
+        assert_eq!(i8::try_from(Decimal::from(-128)), Ok(-128));
+        assert_eq!(i16::try_from(Decimal::from(i16::MIN)), Ok(i16::MIN));
+        assert_eq!(i32::try_from(Decimal::from(-17)), Ok(-17));
+        assert_eq!(i64::try_from(Decimal::from(i64::MIN)), Ok(i64::MIN));
+        assert_eq!(i64::try_from(Decimal::from(i64::MAX)), Ok(i64::MAX));
+        assert_eq!(
+            i128::try_from(Decimal::from_str("-146150163733090291820368").unwrap()),
+            Ok(-146150163733090291820368)
+        );
+
+        assert_eq!(
+            i8::try_from(Decimal::from(128)),
+            Err(TryFromNumberError::Overflow)
+        );
+        assert_eq!(
+            i8::try_from(Decimal::from(-129)),
+            Err(TryFromNumberError::Overflow)
+        );
+        assert_eq!(
+            i64::try_from(Decimal::min().trunc()),
+            Err(TryFromNumberError::Overflow)
+        );
+        assert_eq!(
+            i32::try_from(Decimal::with_scale(-25, 1)),
+            Err(TryFromNumberError::Fraction)
+        );
+    }
+
+    #[test]
+    fn test_try_into_special() {
+        assert_eq!(
+            i32::try_from(Decimal::nan()),
+            Err(TryFromNumberError::Special)
+        );
+        assert_eq!(
+            u8::try_from(Decimal::infinity()),
+            Err(TryFromNumberError::Special)
+        );
+        assert_eq!(
+            i128::try_from(Decimal::neg_infinity()),
+            Err(TryFromNumberError::Special)
+        );
+        assert_eq!(
+            Decimal::nan().to_i64_truncated(),
+            Err(TryFromNumberError::Special)
+        );
+        assert_eq!(
+            Decimal::infinity().to_u128_rounded(),
+            Err(TryFromNumberError::Special)
+        );
+    }
+
+    #[test]
+    fn test_to_primitive_truncated() {
+        assert_eq!(Decimal::with_scale(15, 1).to_u8_truncated(), Ok(1));
+        assert_eq!(Decimal::with_scale(19, 1).to_u16_truncated(), Ok(1));
+        assert_eq!(Decimal::with_scale(-19, 1).to_i32_truncated(), Ok(-1));
+        assert_eq!(Decimal::with_scale(-5, 1).to_i64_truncated(), Ok(0));
+        assert_eq!(Decimal::with_scale(-5, 1).to_u64_truncated(), Ok(0));
+        assert_eq!(Decimal::ulp().to_u128_truncated(), Ok(0));
+        assert_eq!(
+            Decimal::max().to_u128_truncated(),
+            Ok(146150163733090291820368)
+        );
+        assert_eq!(
+            Decimal::min().to_i128_truncated(),
+            Ok(-146150163733090291820368)
+        );
+        assert_eq!(
+            Decimal::with_scale(-15, 1).to_u8_truncated(),
+            Err(TryFromNumberError::Overflow)
+        );
+        assert_eq!(
+            Decimal::with_scale(1285, 1).to_i8_truncated(),
+            Err(TryFromNumberError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_to_primitive_rounded() {
+        assert_eq!(Decimal::with_scale(14, 1).to_u8_rounded(), Ok(1));
+        assert_eq!(Decimal::with_scale(15, 1).to_u8_rounded(), Ok(2));
+        assert_eq!(Decimal::with_scale(25, 1).to_u16_rounded(), Ok(3));
+        assert_eq!(Decimal::with_scale(-25, 1).to_i16_rounded(), Ok(-3));
+        assert_eq!(Decimal::with_scale(-24, 1).to_i32_rounded(), Ok(-2));
+        assert_eq!(
+            Decimal::from_str("0.4999999999999999999999999")
+                .unwrap()
+                .to_i64_rounded(),
+            Ok(0)
+        );
+        assert_eq!(
+            Decimal::from_str("0.5000000000000000000000001")
+                .unwrap()
+                .to_i64_rounded(),
+            Ok(1)
+        );
+        assert_eq!(Decimal::ulp().to_u128_rounded(), Ok(0));
+        assert_eq!(
+            Decimal::max().to_u128_rounded(),
+            Ok(146150163733090291820368)
+        );
+        assert_eq!(
+            Decimal::min().to_i128_rounded(),
+            Ok(-146150163733090291820368)
+        );
+        assert_eq!(Decimal::with_scale(-4, 1).to_u8_rounded(), Ok(0));
+        assert_eq!(
+            Decimal::with_scale(-5, 1).to_u8_rounded(),
+            Err(TryFromNumberError::Overflow)
+        );
+        assert_eq!(
+            Decimal::with_scale(2555, 1).to_u8_rounded(),
+            Err(TryFromNumberError::Overflow)
+        );
+        assert_eq!(Decimal::with_scale(-1284, 1).to_i8_rounded(), Ok(-128));
+    }
+
     #[test]
     fn test_from_le_units() {
         assert_eq!(
@@ -1428,6 +2126,291 @@ mod prim {
             ]
         );
     }
+
+    #[test]
+    fn test_byte_len() {
+        assert_eq!(Decimal::BYTE_LEN, 21);
+        let bytes: [u8; Decimal::BYTE_LEN] = Decimal::one().to_be_bytes();
+        assert_eq!(Decimal::from_be_bytes(&bytes), Ok(Decimal::one()));
+    }
+
+    #[test]
+    fn test_write_be_bytes() {
+        let v = Decimal::from_le_units(true, [0x51_52_53_54, 4, 0x31_32_33_34, 2, 1]);
+        let mut buf = [0xaa; 24];
+        assert_eq!(v.write_be_bytes(&mut buf[2..]), Ok(21));
+        assert_eq!(buf[..2], [0xaa, 0xaa]);
+        assert_eq!(buf[2..23], v.to_be_bytes());
+        assert_eq!(buf[23], 0xaa);
+        assert_eq!(
+            v.write_be_bytes(&mut buf[4..]),
+            Err(FromBytesError::BufferTooShort)
+        );
+        assert_eq!(buf[23], 0xaa);
+    }
+
+    #[test]
+    fn test_write_le_and_ne_bytes() {
+        let v = Decimal::from_le_units(true, [6, 0x51_52_53_54, 4, 0x31_32_33_34, 2]);
+        let mut buf = [0; 21];
+        assert_eq!(v.write_le_bytes(&mut buf), Ok(21));
+        assert_eq!(buf, v.to_le_bytes());
+        assert_eq!(v.write_ne_bytes(&mut buf), Ok(21));
+        assert_eq!(buf, v.to_ne_bytes());
+        assert_eq!(
+            v.write_le_bytes(&mut buf[1..]),
+            Err(FromBytesError::BufferTooShort)
+        );
+        assert_eq!(
+            v.write_ne_bytes(&mut []),
+            Err(FromBytesError::BufferTooShort)
+        );
+    }
+
+    #[test]
+    fn test_read_be_bytes() {
+        let v = Decimal::from_le_units(true, [0x51_52_53_54, 4, 0x31_32_33_34, 2, 1]);
+        let mut buf = vec![0xff, 0xff];
+        buf.extend_from_slice(&v.to_be_bytes());
+        buf.extend_from_slice(&Decimal::nan().to_be_bytes());
+        assert_eq!(Decimal::read_be_bytes(&buf[2..]), Ok((v, 21)));
+        assert!(Decimal::read_be_bytes(&buf[23..]).unwrap().0.is_nan());
+        assert_eq!(
+            Decimal::read_be_bytes(&buf[24..]),
+            Err(FromBytesError::BufferTooShort)
+        );
+        assert_eq!(
+            Decimal::read_be_bytes(&buf[..21]),
+            Err(FromBytesError::InvalidFlags)
+        );
+    }
+
+    #[test]
+    fn test_read_le_and_ne_bytes() {
+        let v = Decimal::from_le_units(false, [6, 0x51_52_53_54, 4, 0x31_32_33_34, 2]);
+        let mut buf = v.to_le_bytes().to_vec();
+        buf.push(0xff);
+        assert_eq!(Decimal::read_le_bytes(&buf), Ok((v, 21)));
+        assert_eq!(
+            Decimal::read_le_bytes(&buf[1..]),
+            Err(FromBytesError::InvalidFlags)
+        );
+        assert_eq!(
+            Decimal::read_le_bytes(&buf[..20]),
+            Err(FromBytesError::BufferTooShort)
+        );
+        let buf = v.to_ne_bytes();
+        assert_eq!(Decimal::read_ne_bytes(&buf), Ok((v, 21)));
+        assert_eq!(
+            Decimal::read_ne_bytes(&buf[..20]),
+            Err(FromBytesError::BufferTooShort)
+        );
+    }
+
+    #[test]
+    fn test_to_sortable_bytes() {
+        assert_eq!(
+            Decimal::from_le_units(false, [0x51_52_53_54, 4, 0x31_32_33_34, 2, 1])
+                .to_sortable_bytes(),
+            [
+                0x02, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x31, 0x32, 0x33, 0x34, 0x00,
+                0x00, 0x00, 0x04, 0x51, 0x52, 0x53, 0x54
+            ]
+        );
+        assert_eq!(
+            Decimal::from_le_units(true, [0x51_52_53_54, 4, 0x31_32_33_34, 2, 1])
+                .to_sortable_bytes(),
+            [
+                0x01, 0xff, 0xff, 0xff, 0xfe, 0xff, 0xff, 0xff, 0xfd, 0xce, 0xcd, 0xcc, 0xcb, 0xff,
+                0xff, 0xff, 0xfb, 0xae, 0xad, 0xac, 0xab
+            ]
+        );
+        let mut zero = [0; 21];
+        zero[0] = 0x02;
+        assert_eq!(Decimal::zero().to_sortable_bytes(), zero);
+        assert_eq!(Decimal::neg_infinity().to_sortable_bytes()[0], 0);
+        assert_eq!(Decimal::infinity().to_sortable_bytes()[0], 3);
+        assert_eq!(Decimal::nan().to_sortable_bytes()[0], 4);
+    }
+
+    #[test]
+    fn test_sortable_bytes_order() {
+        let mut values = vec![
+            Decimal::nan(),
+            Decimal::infinity(),
+            Decimal::neg_infinity(),
+            Decimal::max(),
+            Decimal::min(),
+            Decimal::zero(),
+            Decimal::ulp(),
+            -Decimal::ulp(),
+        ];
+        for s in &[
+            "1",
+            "-1",
+            "0.5",
+            "-0.5",
+            "4294967296",
+            "-4294967296",
+            "4294967295.9",
+            "-4294967295.9",
+            "123.456",
+            "-123.457",
+        ] {
+            values.push(Decimal::from_str(s).unwrap());
+        }
+        let mut by_bytes = values.clone();
+        by_bytes.sort_by_key(|v| v.to_sortable_bytes());
+        values.sort_by(|a, b| a.total_cmp(b));
+        assert_eq!(by_bytes.len(), values.len());
+        for (a, b) in by_bytes.iter().zip(values.iter()) {
+            assert_eq!(a.total_cmp(b), std::cmp::Ordering::Equal);
+        }
+        for v in &values {
+            let restored = Decimal::from_sortable_bytes(&v.to_sortable_bytes()).unwrap();
+            assert_eq!(restored.total_cmp(v), std::cmp::Ordering::Equal);
+        }
+    }
+
+    #[test]
+    fn test_compact_encoding() {
+        let mut buf = [0; Decimal::MAX_COMPACT_LEN];
+        assert_eq!(Decimal::MAX_COMPACT_LEN, 21);
+        let v = Decimal::from_le_units(true, [0x51_52_53_54, 4, 0, 0, 0]);
+        assert_eq!(v.encode_compact(&mut buf), 6);
+        assert_eq!(buf[..6], [0x29, 0x04, 0x51, 0x52, 0x53, 0x54]);
+        assert_eq!(Decimal::decode_compact(&buf), Ok((v, 6)));
+
+        let v = Decimal::from_str("1.5").unwrap();
+        let n = v.encode_compact(&mut buf);
+        assert!(n < v.to_be_bytes().len());
+        assert_eq!(Decimal::decode_compact(&buf[..n]), Ok((v, n)));
+        assert_eq!(Decimal::max().encode_compact(&mut buf), 21);
+        assert_eq!(Decimal::decode_compact(&buf), Ok((Decimal::max(), 21)));
+    }
+
+    #[test]
+    fn test_from_sortable_bytes_invalid() {
+        let mut bytes = Decimal::one().to_sortable_bytes();
+        bytes[0] = 5;
+        assert_eq!(
+            Decimal::from_sortable_bytes(&bytes),
+            Err(FromBytesError::InvalidFlags)
+        );
+        // All-ones body of a negative number denotes negative zero
+        let mut bytes = [0xff; 21];
+        bytes[0] = 1;
+        assert_eq!(
+            Decimal::from_sortable_bytes(&bytes),
+            Err(FromBytesError::InvalidFormat)
+        );
+        // Special values with trailing bytes
+        for v in &[Decimal::neg_infinity(), Decimal::infinity(), Decimal::nan()] {
+            let mut bytes = v.to_sortable_bytes();
+            assert!(Decimal::from_sortable_bytes(&bytes).is_ok());
+            bytes[20] = 1;
+            assert_eq!(
+                Decimal::from_sortable_bytes(&bytes),
+                Err(FromBytesError::InvalidFormat)
+            );
+        }
+    }
+
+    #[test]
+    fn test_scaled_i128() {
+        let ulp = Decimal::ulp();
+        assert_eq!(ulp.to_scaled_i128(), Ok(1));
+        assert_eq!((-ulp).to_scaled_i128(), Ok(-1));
+        assert_eq!(Decimal::one().to_scaled_i128(), Ok(10_i128.pow(25)));
+        let max = Decimal::from_le_units(
+            false,
+            [0xffff_ffff, 0xffff_ffff, 0xffff_ffff, 0x7fff_ffff, 0],
+        );
+        assert_eq!(max.to_scaled_i128(), Ok(i128::MAX));
+        assert_eq!(
+            (max + ulp).to_scaled_i128(),
+            Err(TryFromNumberError::Overflow)
+        );
+        assert_eq!((-max - ulp).to_scaled_i128(), Ok(i128::MIN));
+        assert_eq!(
+            (-max - ulp - ulp).to_scaled_i128(),
+            Err(TryFromNumberError::Overflow)
+        );
+        assert_eq!(
+            Decimal::max().to_scaled_i128(),
+            Err(TryFromNumberError::Overflow)
+        );
+        assert_eq!(
+            Decimal::nan().to_scaled_i128(),
+            Err(TryFromNumberError::Special)
+        );
+        for v in &[i128::MIN, -1, 0, 1, 10_i128.pow(25), i128::MAX] {
+            assert_eq!(Decimal::from_scaled_i128(*v).to_scaled_i128(), Ok(*v));
+        }
+    }
+
+    #[test]
+    fn test_parts() {
+        let v = Decimal::from_str("-12345.6789").unwrap();
+        let (neg, magnitude) = v.to_parts();
+        assert!(neg);
+        assert_eq!(v.as_le_units(), &magnitude[..]);
+        assert_eq!(Decimal::from_parts(neg, magnitude), v);
+        assert_eq!(Decimal::from_parts(false, [1, 0, 0, 0, 0]), Decimal::ulp());
+        assert_eq!(Decimal::max().as_le_units(), &[0xffff_ffff; 5]);
+    }
+
+    #[test]
+    fn test_twos_complement() {
+        let values = [
+            Decimal::zero(),
+            Decimal::ulp(),
+            -Decimal::ulp(),
+            Decimal::from_str("-12345.6789").unwrap(),
+            Decimal::max(),
+            Decimal::min(),
+        ];
+        for v in &values {
+            let (bytes, scale) = v.to_twos_complement_be().unwrap();
+            assert_eq!(bytes.len(), 21);
+            assert_eq!(scale, 25);
+            assert_eq!(bytes[0] & 0x80 != 0, v.is_sign_negative());
+            assert_eq!(
+                Decimal::from_twos_complement_be(&bytes, scale, RoundingMode::Down),
+                Ok(*v)
+            );
+        }
+        assert_eq!(
+            Decimal::from_twos_complement_be(&[0x01], 60, RoundingMode::Up),
+            Ok(Decimal::ulp())
+        );
+        assert_eq!(
+            Decimal::from_twos_complement_be(&[0x01], 60, RoundingMode::Down),
+            Ok(Decimal::zero())
+        );
+        assert_eq!(
+            Decimal::from_twos_complement_be(&[0x01], 0, RoundingMode::Down),
+            Ok(Decimal::one())
+        );
+        assert_eq!(
+            Decimal::from_twos_complement_be(&[0x01], -3, RoundingMode::Down),
+            Ok(Decimal::from(1000))
+        );
+        assert_eq!(
+            Decimal::from_twos_complement_be(&[0xff, 0x85], -20, RoundingMode::Down),
+            Ok(Decimal::from_str("-12300000000000000000000").unwrap())
+        );
+        assert_eq!(
+            Decimal::from_twos_complement_be(&[0x01], -30, RoundingMode::Down),
+            Err(TryFromNumberError::Overflow)
+        );
+        let mut bytes = [0x00; 22];
+        bytes[1] = 0x01;
+        assert_eq!(
+            Decimal::from_twos_complement_be(&bytes, 25, RoundingMode::Down),
+            Err(TryFromNumberError::Overflow)
+        );
+    }
 }
 
 #[cfg(test)]
@@ -1748,3 +2731,200 @@ mod math {
         assert_eq!(Decimal::with_scale(1, 1).powi(-24), Decimal::infinity());
     }
 }
+
+#[cfg(test)]
+mod ieee {
+    use std::str::FromStr;
+
+    use super::decimal::*;
+
+    #[test]
+    fn test_rounding_to_coefficient() {
+        let v = Decimal::from_str("1000000000.0000000000000000000000005").unwrap();
+        let even = v.to_decimal128_bid(RoundingMode::HalfEven).unwrap();
+        assert_eq!(
+            Decimal::from_decimal128_bid(even, RoundingMode::Down),
+            Ok(Decimal::from(1_000_000_000))
+        );
+        let up = v.to_decimal128_dpd(RoundingMode::HalfUp).unwrap();
+        assert_eq!(
+            Decimal::from_decimal128_dpd(up, RoundingMode::Down),
+            Ok(Decimal::from_str("1000000000.000000000000000000000001").unwrap())
+        );
+        let v = Decimal::from_str("-99999999999999999.99").unwrap();
+        let rounded = v.to_decimal64_bid(RoundingMode::HalfEven).unwrap();
+        assert_eq!(
+            Decimal::from_decimal64_bid(rounded, RoundingMode::Down),
+            Ok(Decimal::from_str("-100000000000000000").unwrap())
+        );
+        let rounded = v.to_decimal64_dpd(RoundingMode::Ceiling).unwrap();
+        assert_eq!(
+            Decimal::from_decimal64_dpd(rounded, RoundingMode::Down),
+            Ok(Decimal::from_str("-99999999999999990").unwrap())
+        );
+    }
+}
+
+#[cfg(test)]
+mod google {
+    use std::str::FromStr;
+
+    use super::decimal::*;
+
+    fn d(s: &str) -> Decimal {
+        Decimal::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_to_units_nanos() {
+        assert_eq!(
+            d("1.75").to_units_nanos(RoundingMode::Down),
+            Ok((1, 750_000_000))
+        );
+        assert_eq!(
+            d("-1.75").to_units_nanos(RoundingMode::Down),
+            Ok((-1, -750_000_000))
+        );
+        assert_eq!(
+            d("-0.75").to_units_nanos(RoundingMode::Down),
+            Ok((0, -750_000_000))
+        );
+        assert_eq!(d("-5").to_units_nanos(RoundingMode::Down), Ok((-5, 0)));
+        assert_eq!(Decimal::zero().to_units_nanos(RoundingMode::Up), Ok((0, 0)));
+        assert_eq!(
+            Decimal::nan().to_units_nanos(RoundingMode::Down),
+            Err(TryFromNumberError::Special)
+        );
+        assert_eq!(
+            Decimal::infinity().to_units_nanos(RoundingMode::Down),
+            Err(TryFromNumberError::Special)
+        );
+    }
+
+    #[test]
+    fn test_to_units_nanos_rounding() {
+        let v = d("-2.0000000005");
+        assert_eq!(v.to_units_nanos(RoundingMode::Down), Ok((-2, 0)));
+        assert_eq!(v.to_units_nanos(RoundingMode::HalfEven), Ok((-2, 0)));
+        assert_eq!(v.to_units_nanos(RoundingMode::HalfUp), Ok((-2, -1)));
+        assert_eq!(v.to_units_nanos(RoundingMode::Floor), Ok((-2, -1)));
+        assert_eq!(v.to_units_nanos(RoundingMode::Ceiling), Ok((-2, 0)));
+        assert_eq!(
+            d("0.9999999999").to_units_nanos(RoundingMode::HalfUp),
+            Ok((1, 0))
+        );
+        assert_eq!(Decimal::ulp().to_units_nanos(RoundingMode::Up), Ok((0, 1)));
+    }
+
+    #[test]
+    fn test_from_units_nanos() {
+        assert_eq!(
+            Decimal::from_units_nanos(1, 750_000_000, RoundingMode::Down),
+            Ok(d("1.75"))
+        );
+        assert_eq!(
+            Decimal::from_units_nanos(-1, -750_000_000, RoundingMode::Down),
+            Ok(d("-1.75"))
+        );
+        assert_eq!(
+            Decimal::from_units_nanos(0, -1, RoundingMode::Down),
+            Ok(d("-0.000000001"))
+        );
+        assert_eq!(
+            Decimal::from_units_nanos(-3, 0, RoundingMode::Down),
+            Ok(d("-3"))
+        );
+        assert_eq!(
+            Decimal::from_units_nanos(0, 0, RoundingMode::Down),
+            Ok(Decimal::zero())
+        );
+    }
+
+    #[test]
+    fn test_from_units_nanos_errors() {
+        assert_eq!(
+            Decimal::from_units_nanos(1, -1, RoundingMode::Down),
+            Err(TryFromNumberError::Invalid)
+        );
+        assert_eq!(
+            Decimal::from_units_nanos(-1, 1, RoundingMode::Down),
+            Err(TryFromNumberError::Invalid)
+        );
+        assert_eq!(
+            Decimal::from_units_nanos(0, 1_000_000_000, RoundingMode::Down),
+            Err(TryFromNumberError::Invalid)
+        );
+        assert_eq!(
+            Decimal::from_units_nanos(0, i32::MIN, RoundingMode::Down),
+            Err(TryFromNumberError::Invalid)
+        );
+    }
+
+    #[test]
+    fn test_units_nanos_round_trip() {
+        for v in &[
+            d("0.000000001"),
+            d("-123456.789"),
+            d("9223372036854775807.999999999"),
+        ] {
+            let (units, nanos) = v.to_units_nanos(RoundingMode::Down).unwrap();
+            assert_eq!(
+                Decimal::from_units_nanos(units, nanos, RoundingMode::Down),
+                Ok(*v)
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_google_decimal() {
+        assert_eq!(
+            Decimal::from_google_decimal("1.75", RoundingMode::Down),
+            Ok(d("1.75"))
+        );
+        assert_eq!(
+            Decimal::from_google_decimal("-.5", RoundingMode::Down),
+            Ok(d("-0.5"))
+        );
+        assert_eq!(
+            Decimal::from_google_decimal("+12.", RoundingMode::Down),
+            Ok(d("12"))
+        );
+        assert_eq!(
+            Decimal::from_google_decimal("175E-2", RoundingMode::Down),
+            Ok(d("1.75"))
+        );
+        assert_eq!(
+            Decimal::from_google_decimal("0.00175e+3", RoundingMode::Down),
+            Ok(d("1.75"))
+        );
+        assert_eq!(
+            Decimal::from_google_decimal("-0e10", RoundingMode::Down),
+            Ok(Decimal::zero())
+        );
+    }
+
+    #[test]
+    fn test_from_google_decimal_errors() {
+        for s in &["1.2.3", "NaN", "1e"] {
+            assert_eq!(
+                Decimal::from_google_decimal(s, RoundingMode::Down),
+                Err(ParseNumberError::InvalidFormat)
+            );
+        }
+    }
+
+    #[test]
+    fn test_display_is_valid_google_decimal() {
+        for v in &[
+            d("0"),
+            d("-0.000000000001"),
+            d("123.45"),
+            Decimal::max(),
+            Decimal::min(),
+        ] {
+            let s = v.to_string();
+            assert!(fdec::google::is_valid_decimal(&s), "{}", s);
+            assert_eq!(Decimal::from_google_decimal(&s, RoundingMode::Down), Ok(*v));
+        }
+    }
+}
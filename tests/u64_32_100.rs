@@ -9,6 +9,7 @@ fdec64! {
 }
 
 use dec::*;
+use std::convert::TryFrom;
 use std::str::FromStr;
 
 #[test]
@@ -54,3 +55,267 @@ fn test_constants() {
     assert_eq!(dec::consts::E.to_string(), "2.7182818284590452353602874713526624977572470936999595749669676277240766303535475945713821785251664274");
     assert_eq!(dec::consts::PI.to_string(), "3.141592653589793238462643383279502884197169399375105820974944592307816406286208998628034825342117068");
 }
+
+#[test]
+fn test_from_128_bit_prim() {
+    assert_eq!(
+        Decimal::from(u128::MAX),
+        Decimal::from_str("340282366920938463463374607431768211455").unwrap()
+    );
+    assert_eq!(
+        Decimal::from(i128::MIN),
+        Decimal::from_str("-170141183460469231731687303715884105728").unwrap()
+    );
+    assert_eq!(
+        Decimal::with_scale(i128::MAX, 100),
+        Decimal::from_str(&format!("0.{}{}", "0".repeat(61), i128::MAX)).unwrap()
+    );
+    assert_eq!(
+        Decimal::from(i128::MIN) * 2_i128,
+        Decimal::from(u128::MAX) * -1 - 1
+    );
+}
+
+#[test]
+fn test_from_pointer_sized_prim() {
+    assert_eq!(
+        Decimal::from(usize::MAX),
+        Decimal::from_str(&usize::MAX.to_string()).unwrap()
+    );
+    assert_eq!(
+        Decimal::from(isize::MIN),
+        Decimal::from_str(&isize::MIN.to_string()).unwrap()
+    );
+    assert_eq!(
+        Decimal::with_scale(7_usize, 100) * 2_isize,
+        Decimal::ulp() * 14
+    );
+}
+
+#[test]
+fn test_to_primitive() {
+    assert_eq!(u8::try_from(Decimal::from(200)), Ok(200));
+    assert_eq!(i64::try_from(Decimal::from(i64::MIN)), Ok(i64::MIN));
+    assert_eq!(
+        u128::try_from(Decimal::from_str("340282366920938463463374607431768211455").unwrap()),
+        Ok(u128::MAX)
+    );
+    assert_eq!(
+        u128::try_from(Decimal::from_str("340282366920938463463374607431768211456").unwrap()),
+        Err(TryFromNumberError::Overflow)
+    );
+    assert_eq!(
+        Decimal::from_str("-170141183460469231731687303715884105728.5")
+            .unwrap()
+            .to_i128_truncated(),
+        Ok(i128::MIN)
+    );
+    assert_eq!(
+        Decimal::from_str("-170141183460469231731687303715884105728.5")
+            .unwrap()
+            .to_i128_rounded(),
+        Err(TryFromNumberError::Overflow)
+    );
+    assert_eq!(Decimal::ulp().to_u64_rounded(), Ok(0));
+}
+
+#[test]
+fn test_to_float() {
+    assert_eq!(Decimal::ulp().to_f64(), 1e-100);
+    assert_eq!(Decimal::ulp().to_f32(), 0.0);
+    assert_eq!(
+        Decimal::max().to_f64(),
+        Decimal::max().to_string().parse::<f64>().unwrap()
+    );
+    assert_eq!(Decimal::max().to_f32(), f32::INFINITY);
+    assert_eq!(Decimal::min().to_f32(), f32::NEG_INFINITY);
+    assert_eq!((-*dec::consts::PI).to_f64(), -std::f64::consts::PI);
+}
+
+#[test]
+fn test_from_float() {
+    use fdec::RoundingMode::*;
+    assert_eq!(
+        Decimal::from_f64_exact(1e200, Down),
+        Decimal::from_str(&format!("{:.0}", 1e200)).unwrap()
+    );
+    assert_eq!(
+        Decimal::from_f64_exact(f64::MIN_POSITIVE, Up),
+        Decimal::ulp()
+    );
+    assert_eq!(
+        Decimal::from_f64_shortest(-1.25e200, Down),
+        Decimal::from_str(&format!("-125{}", "0".repeat(198))).unwrap()
+    );
+    assert_eq!(Decimal::from_f64_shortest(1e-100, HalfEven), Decimal::ulp());
+    assert_eq!(Decimal::from_f64_shortest(1e209, Down), Decimal::infinity());
+    assert_eq!(Decimal::from_f64_shortest(1e-101, Ceiling), Decimal::ulp());
+}
+
+#[test]
+fn test_compact_encoding() {
+    let mut buf = [0; Decimal::MAX_COMPACT_LEN];
+    assert_eq!(Decimal::MAX_COMPACT_LEN, 130);
+
+    assert_eq!(Decimal::zero().encode_compact(&mut buf), 1);
+    assert_eq!(buf[0], 0x00);
+    assert_eq!(Decimal::nan().encode_compact(&mut buf), 1);
+    assert_eq!(buf[0], 0x02);
+    assert_eq!(Decimal::neg_infinity().encode_compact(&mut buf), 1);
+    assert_eq!(buf[0], 0x05);
+    assert_eq!(Decimal::ulp().encode_compact(&mut buf), 2);
+    assert_eq!(buf[..2], [0x08, 0x01]);
+    assert_eq!((-Decimal::ulp()).encode_compact(&mut buf), 2);
+    assert_eq!(buf[..2], [0x09, 0x01]);
+
+    // 10^100 takes 42 bytes, which requires an extended length
+    assert_eq!(Decimal::one().encode_compact(&mut buf), 44);
+    assert_eq!(buf[..2], [0xf8, 0x0b]);
+    assert_eq!(buf[2..44], Decimal::one().to_be_bytes()[87..]);
+    assert_eq!(Decimal::min().encode_compact(&mut buf), 130);
+    assert_eq!(buf[..3], [0xf9, 0x61, 0xff]);
+
+    let values = [
+        Decimal::zero(),
+        Decimal::one(),
+        Decimal::ulp(),
+        -Decimal::ulp(),
+        Decimal::max(),
+        Decimal::min(),
+        Decimal::infinity(),
+        Decimal::neg_infinity(),
+        Decimal::from_str("-1.5").unwrap(),
+        Decimal::from_str("123456789.000000001").unwrap(),
+    ];
+    for v in &values {
+        let n = v.encode_compact(&mut buf);
+        assert_eq!(Decimal::decode_compact(&buf[..n]), Ok((*v, n)));
+    }
+    let n = Decimal::nan().encode_compact(&mut buf);
+    assert!(Decimal::decode_compact(&buf[..n]).unwrap().0.is_nan());
+}
+
+#[test]
+fn test_decode_compact_errors() {
+    assert_eq!(
+        Decimal::decode_compact(&[]),
+        Err(FromBytesError::BufferTooShort)
+    );
+    assert_eq!(
+        Decimal::decode_compact(&[0xf8]),
+        Err(FromBytesError::BufferTooShort)
+    );
+    assert_eq!(
+        Decimal::decode_compact(&[0x10, 0x01]),
+        Err(FromBytesError::BufferTooShort)
+    );
+    assert_eq!(
+        Decimal::decode_compact(&[0xf8, 0x62]),
+        Err(FromBytesError::Overflow)
+    );
+    assert_eq!(
+        Decimal::decode_compact(&[0xf8, 0xe1, 0x00]),
+        Err(FromBytesError::Overflow)
+    );
+    // Lengths that are too long for the type are rejected before they're read completely
+    assert_eq!(
+        Decimal::decode_compact(&[0xf8, 0x80]),
+        Err(FromBytesError::Overflow)
+    );
+    assert_eq!(
+        Decimal::decode_compact(&[0x0a, 0x01]),
+        Err(FromBytesError::InvalidFlags)
+    );
+    assert_eq!(
+        Decimal::decode_compact(&[0x06]),
+        Err(FromBytesError::InvalidFlags)
+    );
+}
+
+#[test]
+fn test_compact_io() {
+    let values = [
+        Decimal::from_str("1.5").unwrap(),
+        Decimal::max(),
+        Decimal::neg_infinity(),
+        Decimal::from(-42),
+    ];
+    let mut out = Vec::new();
+    let mut total = 0;
+    for v in &values {
+        total += v.write_compact(&mut out).unwrap();
+    }
+    assert_eq!(out.len(), total);
+
+    let mut input = &out[..];
+    for v in &values {
+        assert_eq!(Decimal::read_compact(&mut input).unwrap(), *v);
+    }
+    assert!(input.is_empty());
+    assert_eq!(
+        Decimal::read_compact(&mut input).unwrap_err().kind(),
+        std::io::ErrorKind::UnexpectedEof
+    );
+    assert_eq!(
+        Decimal::read_compact(&mut &[0xf8, 0x62][..])
+            .unwrap_err()
+            .kind(),
+        std::io::ErrorKind::InvalidData
+    );
+    assert_eq!(
+        Decimal::read_compact(&mut &[0xf8, 0x80, 0x80][..])
+            .unwrap_err()
+            .kind(),
+        std::io::ErrorKind::InvalidData
+    );
+}
+
+#[test]
+fn test_decimal128() {
+    let max = Decimal::max()
+        .to_decimal128_bid(RoundingMode::Down)
+        .unwrap();
+    assert_eq!(
+        Decimal::from_decimal128_bid(max, RoundingMode::Down),
+        Ok(Decimal::from_str("17976931348623159077293051907890240000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000").unwrap())
+    );
+    // Rounding up the largest value doesn't fit
+    let max = Decimal::max().to_decimal128_bid(RoundingMode::Up).unwrap();
+    assert_eq!(
+        Decimal::from_decimal128_bid(max, RoundingMode::Down),
+        Err(TryFromNumberError::Overflow)
+    );
+    let min = Decimal::min().to_decimal128_dpd(RoundingMode::Up).unwrap();
+    assert_eq!(
+        Decimal::from_decimal128_dpd(min, RoundingMode::Down),
+        Err(TryFromNumberError::Overflow)
+    );
+}
+
+#[test]
+fn test_pg_numeric() {
+    assert_eq!(
+        Decimal::ulp().to_pg_numeric(),
+        [0, 1, 0xff, 0xe7, 0, 0, 0, 100, 0, 1]
+    );
+    assert_eq!(
+        Decimal::from_pg_numeric(&[0, 1, 0xff, 0xe7, 0, 0, 0, 100, 0, 1], RoundingMode::Down),
+        Ok(Decimal::ulp())
+    );
+    // 10^200
+    assert_eq!(
+        Decimal::from_pg_numeric(&[0, 1, 0, 50, 0, 0, 0, 0, 0, 1], RoundingMode::Down),
+        Ok(Decimal::from(10).powi(200))
+    );
+    // 10^212
+    assert_eq!(
+        Decimal::from_pg_numeric(&[0, 1, 0, 53, 0, 0, 0, 0, 0, 1], RoundingMode::Down),
+        Err(FromBytesError::Overflow)
+    );
+    for v in &[Decimal::max(), Decimal::min(), -Decimal::ulp()] {
+        assert_eq!(
+            Decimal::from_pg_numeric(&v.to_pg_numeric(), RoundingMode::Down),
+            Ok(*v)
+        );
+    }
+}
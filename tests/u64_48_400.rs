@@ -0,0 +1,68 @@
+#[macro_use]
+extern crate fdec;
+
+fdec64! {
+    module dec,
+    name Decimal,
+    length 48,
+    scale 400
+}
+
+use dec::*;
+
+#[test]
+fn test_const() {
+    assert_eq!(Decimal::ulp(), Decimal::with_scale(1, 400));
+    assert_eq!(Decimal::one(), Decimal::from(1));
+    assert_eq!(Decimal::max().to_string().find('.'), Some(525));
+}
+
+#[test]
+fn test_decimal64_range() {
+    // 10^-400 is below the smallest decimal64 value
+    assert_eq!(Decimal::ulp().to_decimal64_bid(RoundingMode::Down), Ok(0));
+    assert_eq!(Decimal::ulp().to_decimal64_bid(RoundingMode::Up), Ok(1));
+    assert_eq!(
+        Decimal::from_decimal64_bid(1, RoundingMode::Down),
+        Ok(Decimal::ulp() * 100)
+    );
+    assert_eq!(
+        Decimal::max().to_decimal64_bid(RoundingMode::Down),
+        Err(TryFromNumberError::Overflow)
+    );
+    assert_eq!(
+        Decimal::min().to_decimal64_dpd(RoundingMode::Down),
+        Err(TryFromNumberError::Overflow)
+    );
+}
+
+#[test]
+fn test_decimal128_range() {
+    let ulp = Decimal::ulp()
+        .to_decimal128_bid(RoundingMode::Down)
+        .unwrap();
+    assert_eq!(
+        Decimal::from_decimal128_bid(ulp, RoundingMode::Down),
+        Ok(Decimal::ulp())
+    );
+    let max = Decimal::max()
+        .to_decimal128_dpd(RoundingMode::Down)
+        .unwrap();
+    assert!(Decimal::from_decimal128_dpd(max, RoundingMode::Down).unwrap() <= Decimal::max());
+}
+
+#[test]
+fn test_pg_numeric() {
+    let ulp = [0, 1, 0xff, 0x9c, 0, 0, 0x01, 0x90, 0, 1];
+    assert_eq!(Decimal::ulp().to_pg_numeric(), ulp);
+    assert_eq!(
+        Decimal::from_pg_numeric(&ulp, RoundingMode::Down),
+        Ok(Decimal::ulp())
+    );
+    for v in &[Decimal::max(), -Decimal::ulp()] {
+        assert_eq!(
+            Decimal::from_pg_numeric(&v.to_pg_numeric(), RoundingMode::Down),
+            Ok(*v)
+        );
+    }
+}
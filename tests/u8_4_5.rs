@@ -9,6 +9,7 @@ fdec8! {
 }
 
 use dec::*;
+use std::convert::TryFrom;
 use std::str::FromStr;
 
 #[test]
@@ -106,3 +107,357 @@ fn test_to_le_bytes() {
         [0x05, 0x04, 0x03, 0x02, 0x01]
     );
 }
+
+#[test]
+fn test_byte_slices() {
+    assert_eq!(Decimal::BYTE_LEN, 5);
+    let v = Decimal::from_le_units(true, [5, 4, 3, 2]);
+    let mut buf = [0; 8];
+    assert_eq!(v.write_be_bytes(&mut buf), Ok(5));
+    assert_eq!(buf, [0x01, 0x02, 0x03, 0x04, 0x05, 0, 0, 0]);
+    assert_eq!(Decimal::read_be_bytes(&buf), Ok((v, 5)));
+    assert_eq!(v.write_le_bytes(&mut buf[3..]), Ok(5));
+    assert_eq!(buf, [0x01, 0x02, 0x03, 0x05, 0x04, 0x03, 0x02, 0x01]);
+    assert_eq!(Decimal::read_le_bytes(&buf[3..]), Ok((v, 5)));
+    assert_eq!(
+        v.write_be_bytes(&mut buf[4..]),
+        Err(FromBytesError::BufferTooShort)
+    );
+    assert_eq!(
+        Decimal::read_le_bytes(&buf[4..]),
+        Err(FromBytesError::BufferTooShort)
+    );
+}
+
+#[test]
+fn test_sortable_bytes() {
+    assert_eq!(
+        Decimal::from_le_units(true, [5, 4, 3, 2]).to_sortable_bytes(),
+        [0x01, 0xfd, 0xfc, 0xfb, 0xfa]
+    );
+    assert_eq!(
+        Decimal::from_le_units(false, [5, 4, 3, 2]).to_sortable_bytes(),
+        [0x02, 0x02, 0x03, 0x04, 0x05]
+    );
+    let values = [
+        Decimal::neg_infinity(),
+        Decimal::min(),
+        -Decimal::one(),
+        -Decimal::ulp(),
+        Decimal::zero(),
+        Decimal::ulp(),
+        Decimal::max(),
+        Decimal::infinity(),
+        Decimal::nan(),
+    ];
+    for w in values.windows(2) {
+        assert!(w[0].to_sortable_bytes() < w[1].to_sortable_bytes());
+    }
+    for v in &values[..8] {
+        assert_eq!(Decimal::from_sortable_bytes(&v.to_sortable_bytes()), Ok(*v));
+    }
+}
+
+#[test]
+fn test_compact_encoding() {
+    let mut buf = [0; Decimal::MAX_COMPACT_LEN];
+    assert_eq!(Decimal::MAX_COMPACT_LEN, 5);
+    assert_eq!(
+        Decimal::from_le_units(true, [5, 4, 3, 2]).encode_compact(&mut buf),
+        5
+    );
+    assert_eq!(buf, [0x21, 0x02, 0x03, 0x04, 0x05]);
+    assert_eq!(Decimal::ulp().encode_compact(&mut buf), 2);
+    assert_eq!(buf[..2], [0x08, 0x01]);
+    assert_eq!(
+        Decimal::decode_compact(&[0x09, 0x01, 0xff]),
+        Ok((-Decimal::ulp(), 2))
+    );
+    assert_eq!(
+        Decimal::decode_compact(&[0x28, 1, 2, 3, 4, 5]),
+        Err(FromBytesError::Overflow)
+    );
+    assert_eq!(
+        Decimal::decode_compact(&[0xf8, 0x00]),
+        Err(FromBytesError::Overflow)
+    );
+    let mut input = &[0x11, 0x01, 0x02, 0x00][..];
+    assert_eq!(
+        Decimal::read_compact(&mut input).unwrap(),
+        Decimal::from_le_units(true, [2, 1, 0, 0])
+    );
+    assert_eq!(Decimal::read_compact(&mut input).unwrap(), Decimal::zero());
+}
+
+#[test]
+fn test_twos_complement() {
+    assert_eq!(
+        Decimal::from_le_units(false, [5, 4, 3, 2]).to_twos_complement_be(),
+        Ok(([0x00, 0x02, 0x03, 0x04, 0x05], 5))
+    );
+    assert_eq!(
+        Decimal::from_le_units(true, [5, 4, 3, 2]).to_twos_complement_be(),
+        Ok(([0xff, 0xfd, 0xfc, 0xfb, 0xfb], 5))
+    );
+    assert_eq!(
+        Decimal::min().to_twos_complement_be(),
+        Ok(([0xff, 0x00, 0x00, 0x00, 0x01], 5))
+    );
+    assert_eq!(Decimal::zero().to_twos_complement_be(), Ok(([0; 5], 5)));
+    assert_eq!(
+        Decimal::nan().to_twos_complement_be(),
+        Err(TryFromNumberError::Special)
+    );
+
+    let from = |bytes: &[u8], scale, mode| Decimal::from_twos_complement_be(bytes, scale, mode);
+    assert_eq!(
+        from(&[0xff, 0xfd, 0xfc, 0xfb, 0xfb], 5, RoundingMode::Down),
+        Ok(Decimal::from_le_units(true, [5, 4, 3, 2]))
+    );
+    assert_eq!(
+        from(&[0xff, 0x00, 0x00, 0x00, 0x01], 5, RoundingMode::Down),
+        Ok(Decimal::min())
+    );
+    assert_eq!(
+        from(&[0x0f], 1, RoundingMode::Down),
+        Ok(Decimal::from_str("1.5").unwrap())
+    );
+    assert_eq!(
+        from(&[0x80], 0, RoundingMode::Down),
+        Ok(Decimal::from(-128))
+    );
+    assert_eq!(from(&[], 3, RoundingMode::Down), Ok(Decimal::zero()));
+    assert_eq!(
+        from(
+            &[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe],
+            0,
+            RoundingMode::Down
+        ),
+        Ok(Decimal::from(-2))
+    );
+    // -0.000015
+    let v = [0xff, 0xff, 0xff, 0xf1];
+    assert_eq!(
+        from(&v, 6, RoundingMode::Down),
+        Ok(Decimal::from_str("-0.00001").unwrap())
+    );
+    assert_eq!(
+        from(&v, 6, RoundingMode::HalfEven),
+        Ok(Decimal::from_str("-0.00002").unwrap())
+    );
+    // Negative scales
+    assert_eq!(
+        from(&[0x0c], -2, RoundingMode::Down),
+        Ok(Decimal::from(1200))
+    );
+    assert_eq!(
+        from(&[0xf4], -3, RoundingMode::Down),
+        Ok(Decimal::from(-12000))
+    );
+    assert_eq!(
+        from(&[0x01], -5, RoundingMode::Down),
+        Err(TryFromNumberError::Overflow)
+    );
+    assert_eq!(
+        from(&[0x01, 0x00, 0x00, 0x00, 0x00], 5, RoundingMode::Down),
+        Err(TryFromNumberError::Overflow)
+    );
+    assert_eq!(
+        from(&[0xa7, 0xc6], 0, RoundingMode::Down),
+        Ok(Decimal::from(-22586))
+    );
+    assert_eq!(
+        from(&[0x00, 0xa7, 0xc6], 0, RoundingMode::Down),
+        Err(TryFromNumberError::Overflow)
+    );
+    // Extreme scales
+    for scale in &[
+        isize::MIN,
+        i32::MIN as isize,
+        -(i32::MIN as isize),
+        isize::MAX,
+    ] {
+        assert_eq!(from(&[], *scale, RoundingMode::Up), Ok(Decimal::zero()));
+        assert_eq!(
+            from(&[0x00, 0x00], *scale, RoundingMode::Up),
+            Ok(Decimal::zero())
+        );
+    }
+    for scale in &[isize::MIN, i32::MIN as isize] {
+        assert_eq!(
+            from(&[0x01], *scale, RoundingMode::Down),
+            Err(TryFromNumberError::Overflow)
+        );
+        assert_eq!(
+            from(&[0xff], *scale, RoundingMode::Down),
+            Err(TryFromNumberError::Overflow)
+        );
+    }
+    for scale in &[-(i32::MIN as isize), isize::MAX] {
+        assert_eq!(
+            from(&[0x7f], *scale, RoundingMode::Down),
+            Ok(Decimal::zero())
+        );
+        assert_eq!(
+            from(&[0x7f], *scale, RoundingMode::HalfUp),
+            Ok(Decimal::zero())
+        );
+        assert_eq!(from(&[0x7f], *scale, RoundingMode::Up), Ok(Decimal::ulp()));
+        assert_eq!(
+            from(&[0xff], *scale, RoundingMode::Floor),
+            Ok(-Decimal::ulp())
+        );
+        assert_eq!(
+            from(&[0xff], *scale, RoundingMode::Ceiling),
+            Ok(Decimal::zero())
+        );
+    }
+    // Shifts around the number of digits of the magnitude
+    let v = [0x7f, 0xff, 0xff, 0xff];
+    assert_eq!(
+        from(&v, 14, RoundingMode::HalfUp),
+        Ok(Decimal::from_str("0.00002").unwrap())
+    );
+    assert_eq!(from(&v, 15, RoundingMode::HalfUp), Ok(Decimal::zero()));
+    assert_eq!(from(&v, 17, RoundingMode::Up), Ok(Decimal::ulp()));
+    assert_eq!(from(&v, 18, RoundingMode::Up), Ok(Decimal::ulp()));
+    assert_eq!(from(&v, 18, RoundingMode::HalfUp), Ok(Decimal::zero()));
+    assert_eq!(
+        from(&[0x01], -4, RoundingMode::Down),
+        Ok(Decimal::from(10000))
+    );
+}
+
+#[test]
+fn test_parts() {
+    let v = Decimal::from_parts(true, [5, 4, 3, 2]);
+    assert_eq!(v, Decimal::from_le_units(true, [5, 4, 3, 2]));
+    assert_eq!(v.to_parts(), (true, [5, 4, 3, 2]));
+    assert_eq!(v.as_le_units(), &[5, 4, 3, 2]);
+    assert_eq!(Decimal::from_parts(false, [1, 0, 0, 0]), Decimal::ulp());
+    assert_eq!(
+        Decimal::from_parts(true, [0; 4]).to_parts(),
+        (false, [0; 4])
+    );
+    assert_eq!(Decimal::one().to_parts(), (false, [0xa0, 0x86, 0x01, 0x00]));
+    assert_eq!(Decimal::nan().to_parts(), (false, [0; 4]));
+}
+
+#[test]
+fn test_scaled_i128() {
+    assert_eq!(Decimal::one().to_scaled_i128(), Ok(100_000));
+    assert_eq!(Decimal::min().to_scaled_i128(), Ok(-0xffff_ffff));
+    assert_eq!(Decimal::zero().to_scaled_i128(), Ok(0));
+    assert_eq!(
+        Decimal::infinity().to_scaled_i128(),
+        Err(TryFromNumberError::Special)
+    );
+    assert_eq!(Decimal::from_scaled_i128(1), Decimal::ulp());
+    assert_eq!(
+        Decimal::from_scaled_i128(-150_000),
+        Decimal::from_str("-1.5").unwrap()
+    );
+    assert_eq!(Decimal::from_scaled_i128(0xffff_ffff), Decimal::max());
+    assert_eq!(
+        Decimal::from_scaled_i128(0x1_0000_0000),
+        Decimal::infinity()
+    );
+    assert_eq!(
+        Decimal::from_scaled_i128(i128::MIN),
+        Decimal::neg_infinity()
+    );
+}
+
+#[test]
+fn test_from_128_bit_prim() {
+    assert_eq!(Decimal::from(17_u128), Decimal::from_str("17").unwrap());
+    assert_eq!(
+        Decimal::from(-42949_i128),
+        Decimal::from_str("-42949").unwrap()
+    );
+    assert_eq!(Decimal::from(42950_u128), Decimal::infinity());
+    assert_eq!(Decimal::from(i128::MIN), Decimal::neg_infinity());
+    assert_eq!(Decimal::with_scale(4294967295_u128, 5), Decimal::max());
+    assert_eq!(Decimal::with_scale(-1_i128, 5), -Decimal::ulp());
+    assert_eq!(Decimal::one() + 2_u128, Decimal::from(3));
+}
+
+#[test]
+fn test_from_pointer_sized_prim() {
+    assert_eq!(Decimal::from(17_usize), Decimal::from_str("17").unwrap());
+    assert_eq!(
+        Decimal::from(-42949_isize),
+        Decimal::from_str("-42949").unwrap()
+    );
+    assert_eq!(Decimal::from(42950_usize), Decimal::infinity());
+    assert_eq!(Decimal::from(isize::MIN), Decimal::neg_infinity());
+    assert_eq!(Decimal::with_scale(-1_isize, 5), -Decimal::ulp());
+    assert_eq!(Decimal::one() * 3_usize, Decimal::from(3));
+}
+
+#[test]
+fn test_to_primitive() {
+    assert_eq!(u16::try_from(Decimal::from(42949)), Ok(42949));
+    assert_eq!(i8::try_from(Decimal::from(-128)), Ok(-128));
+    assert_eq!(
+        u8::try_from(Decimal::from(256)),
+        Err(TryFromNumberError::Overflow)
+    );
+    assert_eq!(Decimal::max().to_u32_truncated(), Ok(42949));
+    assert_eq!(Decimal::max().to_u32_rounded(), Ok(42950));
+    assert_eq!(Decimal::min().to_i64_rounded(), Ok(-42950));
+    assert_eq!(
+        u32::try_from(Decimal::max()),
+        Err(TryFromNumberError::Fraction)
+    );
+}
+
+#[test]
+fn test_to_float() {
+    assert_eq!(Decimal::ulp().to_f64(), 0.00001);
+    assert_eq!(Decimal::max().to_f64(), 42949.67295);
+    assert_eq!(Decimal::min().to_f32(), -42949.67295f64 as f32);
+    assert_eq!(Decimal::from_str("-1.5").unwrap().to_f64(), -1.5);
+}
+
+#[test]
+fn test_from_float() {
+    use fdec::RoundingMode::*;
+    assert_eq!(
+        Decimal::from_f64_exact(0.1, Down),
+        Decimal::from_str("0.1").unwrap()
+    );
+    assert_eq!(
+        Decimal::from_f64_exact(0.1, Up),
+        Decimal::from_str("0.10001").unwrap()
+    );
+    assert_eq!(
+        Decimal::from_f64_exact(-3.000005, Floor),
+        Decimal::from_str("-3.00001").unwrap()
+    );
+    assert_eq!(Decimal::from_f64_shortest(42949.67295, Up), Decimal::max());
+    assert_eq!(
+        Decimal::from_f64_shortest(42949.672951, Up),
+        Decimal::infinity()
+    );
+    assert_eq!(
+        Decimal::from_f32_shortest(-1.5, Down),
+        Decimal::from_str("-1.5").unwrap()
+    );
+    assert_eq!(Decimal::from_f64_exact(1e-300, Ceiling), Decimal::ulp());
+}
+
+#[test]
+fn test_sum() {
+    let ulps = (0..1000).map(|_| Decimal::ulp());
+    assert_eq!(ulps.sum::<Decimal>(), Decimal::from_str("0.01").unwrap());
+    let vals = [
+        Decimal::max(),
+        Decimal::max(),
+        Decimal::min(),
+        Decimal::min(),
+    ];
+    assert_eq!(vals.iter().sum::<Decimal>(), Decimal::zero());
+    assert_eq!(vals[..3].iter().sum::<Decimal>(), Decimal::max());
+    assert_eq!(vals[..2].iter().sum::<Decimal>(), Decimal::infinity());
+}
@@ -0,0 +1,120 @@
+#[macro_use]
+extern crate fdec;
+
+fdec64! {
+    module dec,
+    name Decimal,
+    length 2,
+    scale 10,
+    wide module wide,
+    name WideDecimal
+}
+
+fdec8! {
+    module small,
+    name Small,
+    length 3,
+    scale 2,
+    wide module small_wide,
+    name SmallWide
+}
+
+use dec::*;
+use fdec::RoundingMode::*;
+use small::Small;
+use small_wide::SmallWide;
+use std::convert::TryFrom;
+use std::str::FromStr;
+use wide::WideDecimal;
+
+fn d(s: &str) -> Decimal {
+    Decimal::from_str(s).unwrap()
+}
+
+fn w(s: &str) -> WideDecimal {
+    WideDecimal::from_str(s).unwrap()
+}
+
+#[test]
+fn test_wide_type() {
+    assert_eq!(<WideDecimal as Number>::LENGTH, 4);
+    assert_eq!(<WideDecimal as Number>::SCALE, 20);
+    assert_eq!(<SmallWide as Number>::LENGTH, 6);
+    assert_eq!(<SmallWide as Number>::SCALE, 4);
+}
+
+#[test]
+fn test_widening_mul() {
+    assert_eq!(d("1.5").widening_mul(d("2")), w("3"));
+    assert_eq!(
+        d("0.0000000001").widening_mul(d("0.0000000001")),
+        w("0.00000000000000000001")
+    );
+    assert_eq!(
+        d("-1.0000000001").widening_mul(d("3.0000000003")),
+        w("-3.00000000060000000003")
+    );
+    assert_eq!(d("-2").widening_mul(d("-2")), w("4"));
+    assert!(d("-2").widening_mul(Decimal::zero()).is_sign_positive());
+
+    // The product of the extreme values fits into the wide type
+    let max = Decimal::max();
+    let product = max.widening_mul(max);
+    assert_eq!(
+        product.to_string(),
+        (WideDecimal::from(max) * WideDecimal::from(max)).to_string()
+    );
+    assert_eq!(Decimal::min().widening_mul(max), -product);
+    assert_eq!(max * max, Decimal::infinity());
+
+    assert_eq!(
+        Small::from_str("0.05")
+            .unwrap()
+            .widening_mul(Small::from_str("0.05").unwrap()),
+        SmallWide::from_str("0.0025").unwrap()
+    );
+}
+
+#[test]
+fn test_widening_mul_special_values() {
+    assert!(Decimal::nan().widening_mul(d("1")).is_nan());
+    assert!(Decimal::infinity().widening_mul(Decimal::zero()).is_nan());
+    assert_eq!(
+        Decimal::infinity().widening_mul(d("-1")),
+        WideDecimal::neg_infinity()
+    );
+    assert_eq!(
+        Decimal::neg_infinity().widening_mul(Decimal::neg_infinity()),
+        WideDecimal::infinity()
+    );
+}
+
+#[test]
+fn test_narrowing() {
+    let p = d("0.1234567891").widening_mul(d("0.5"));
+    assert_eq!(p, w("0.06172839455"));
+    assert_eq!(Decimal::from_wide(p, HalfEven), Ok(d("0.0617283946")));
+    assert_eq!(Decimal::from_wide(p, HalfDown), Ok(d("0.0617283945")));
+    assert_eq!(Decimal::from_wide(-p, Floor), Ok(d("-0.0617283946")));
+    assert_eq!(Decimal::try_from(p), Err(TryFromNumberError::Fraction));
+    assert_eq!(Decimal::try_from(w("-12.5")), Ok(d("-12.5")));
+    assert_eq!(
+        Decimal::from_wide(Decimal::max().widening_mul(d("2")), Down),
+        Err(TryFromNumberError::Overflow)
+    );
+    assert!(Decimal::from_wide(WideDecimal::nan(), Up).unwrap().is_nan());
+}
+
+#[test]
+fn test_widening() {
+    assert_eq!(WideDecimal::from(d("-1.25")), w("-1.25"));
+    assert_eq!(WideDecimal::from(Decimal::ulp()), w("0.0000000001"));
+    assert_eq!(
+        WideDecimal::from(Decimal::infinity()),
+        WideDecimal::infinity()
+    );
+    assert_eq!(
+        SmallWide::from(Small::max()),
+        SmallWide::from_str("167772.15").unwrap()
+    );
+}